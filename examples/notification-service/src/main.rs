@@ -1,10 +1,12 @@
 use yew::prelude::*;
 
 use yew_bulma_notification::{
-    Color, NotificationConsumer, NotificationProps, NotificationService, Position,
+    Color, NotificationBadge, NotificationBuilder, NotificationCenter, NotificationConfig,
+    NotificationConsumer, NotificationDefaults, NotificationService, Position,
 };
 
 fn main() {
+    yew_bulma_notification::set_panic_hook();
     yew::start_app::<App>();
 }
 
@@ -19,6 +21,7 @@ pub enum Msg {
     NotifyTR,
     NotifyBL,
     NotifyBR,
+    NotifyError,
 
     BridgeMsg,
 }
@@ -42,76 +45,88 @@ impl Component for App {
         match msg {
             Msg::NotifyTL => {
                 self.notification_service.spawn(
-                    NotificationProps::builder()
+                    NotificationBuilder::new()
                         .position(Position::TopLeft)
-                        .header(Some(String::from("Notification Top Left")))
+                        .header("Notification Top Left")
                         .color(Color::Success)
-                        .timeout(Some(std::time::Duration::from_secs(5)))
-                        .children(yew::html::ChildrenRenderer::new(vec![html! {
+                        .body(html! {
                             <>
                                 <p>{"Hello from the top left of the page!"}</p>
                                 <p>{"I use the \"success\" color and time out after 5 seconds"}</p>
                             </>
-                        }]))
+                        })
                         .build(),
                 );
                 false
             }
             Msg::NotifyTR => {
                 self.notification_service.spawn(
-                    NotificationProps::builder()
+                    NotificationBuilder::new()
                         .position(Position::TopRight)
-                        .header(Some(String::from("Notification Top Right")))
-                        .children(yew::html::ChildrenRenderer::new(vec![html! {
+                        .header("Notification Top Right")
+                        .body(html! {
                             <>
                                 <p>{"This is the body of the notification"}</p>
                                 <p>{"Any `html!` output can be placed here"}</p>
                                 <p></p>
                                 <p>{"Hello from the top right of the page!"}</p>
                             </>
-                        }]))
+                        })
                         .build(),
                 );
                 false
             }
             Msg::NotifyBL => {
                 self.notification_service.spawn(
-                    NotificationProps::builder()
+                    NotificationBuilder::new()
                         .position(Position::BottomLeft)
-                        .header(Some(String::from("Notification Bottom Left")))
-                        .children(yew::html::ChildrenRenderer::new(vec![html! {
+                        .header("Notification Bottom Left")
+                        .body(html! {
                             <>
                                 <p>{"This is the body of the notification"}</p>
                                 <p>{"Any `html!` output can be placed here"}</p>
                                 <p></p>
                                 <p>{"Hello from the bottom left of the page!"}</p>
                             </>
-                        }]))
+                        })
                         .build(),
                 );
                 false
             }
             Msg::NotifyBR => {
                 self.notification_service.spawn(
-                    NotificationProps::builder()
+                    NotificationBuilder::new()
                         .position(Position::BottomRight)
-                        .header(Some(String::from("Notification Bottom Right")))
-                        .children(yew::html::ChildrenRenderer::new(vec![html! {
+                        .header("Notification Bottom Right")
+                        .body(html! {
                             <p>{"This is the body of the notification. Any `html!` output can be placed here. This notificaiton has really long content to show the maximum with of"}<code>{"30vh"}</code>{". You can customize the max width with"}<code>{"$ybn-max-width"}</code></p>
-                        }]))
+                        })
                         .build(),
                 );
                 false
             }
 
+            Msg::NotifyError => {
+                self.notification_service
+                    .error("Something went wrong", "Plain text works as a body too");
+                false
+            }
+
             Msg::BridgeMsg => false,
         }
     }
 
     fn view(&self) -> Html {
+        // Success notifications time out after 5 seconds, unless they set their own timeout
+        let config = NotificationConfig::new().with_color(
+            Color::Success,
+            NotificationDefaults::new().timeout(std::time::Duration::from_secs(5)),
+        );
         html! {
             <main class="ybn-parent">
                 <h1>{"Notification Service Example"}</h1>
+                <NotificationBadge />
+                <NotificationCenter />
                 <p>{"Click the below buttons to spawn a notification at the given location"}</p>
                 <table>
                     <tr>
@@ -122,9 +137,12 @@ impl Component for App {
                         <td><button onclick={self.link.callback(|_| Msg::NotifyBL)}>{"Notify Bottom Left"}</button></td>
                         <td><button onclick={self.link.callback(|_| Msg::NotifyBR)}>{"Notify Bottom Right"}</button></td>
                     </tr>
+                    <tr>
+                        <td><button onclick={self.link.callback(|_| Msg::NotifyError)}>{"Notify Error"}</button></td>
+                    </tr>
                 </table>
 
-                <NotificationConsumer />
+                <NotificationConsumer config={config} />
             </main>
         }
     }
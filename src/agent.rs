@@ -1,70 +1,183 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use yew::worker::*;
+use yew::Callback;
 
+use crate::events::{NotificationEvent, NotificationEventKind};
+use crate::history::{HistoryEntry, NotificationHistory};
+use crate::state::NotificationOutcome;
 use crate::NotificationProps;
 
 pub struct NotificationAgent {
     link: AgentLink<Self>,
-    consumers: HashSet<HandlerId>,
+    /// Registered consumers and the scope they render, if any
+    consumers: HashMap<HandlerId, Option<String>>,
+    history_listeners: HashSet<HandlerId>,
+    observers: HashSet<HandlerId>,
+    /// Receive everything sent to consumers, whatever its scope
+    recorders: HashSet<HandlerId>,
+    history: NotificationHistory,
+    /// Ids of the tagged notifications that are currently displayed
+    tagged: HashMap<String, usize>,
 }
 
 pub enum NotificationAgentInput {
     RegisterConsumer,
+    /// Register a consumer that only renders notifications targeted at the
+    /// given scope
+    RegisterScopedConsumer(String),
     New(NotificationProps),
     NewTagged(NotificationProps, String),
+    /// Spawn a notification in the given scope. If no consumer is registered
+    /// with that scope, the default (unscoped) consumers render it instead
+    NewInScope(NotificationProps, String),
+    /// Spawn a tagged notification (props, tag, scope) in the given scope
+    NewTaggedInScope(NotificationProps, String, String),
+    /// Replace the properties of a tagged notification that is still displayed
+    UpdateTagged(NotificationProps, String),
     CloseTagged(String),
+
+    /// Receive the notification history whenever it changes
+    RegisterHistory,
+    /// Mark all entries in the history as read
+    MarkHistoryRead,
+    ClearHistory,
+
+    /// Receive a [`NotificationEvent`] for everything that happens to a notification
+    RegisterObserver,
+    /// Receive a copy of every output sent to consumers, in any scope,
+    /// without rendering notifications as a consumer. Used by the testing
+    /// `RecordingConsumer`
+    RegisterRecorder,
 }
 
 pub enum NotificationAgentOutput {
     New(NotificationProps),
     NewTagged(NotificationProps, String),
+    UpdateTagged(NotificationProps, String),
     CloseTagged(String),
+
+    History(Vec<HistoryEntry>),
+    Event(NotificationEvent),
+}
+
+/// Reported back by a notification's wrapped callbacks, carrying the
+/// caller's original callback
+pub struct Msg {
+    id: usize,
+    tag: Option<String>,
+    kind: NotificationEventKind,
+    callback: Option<Callback<()>>,
 }
 
 impl Agent for NotificationAgent {
     type Reach = Context<Self>;
-    type Message = ();
+    type Message = Msg;
     type Input = NotificationAgentInput;
     type Output = NotificationAgentOutput;
 
     fn create(link: AgentLink<Self>) -> Self {
         Self {
             link,
-            consumers: HashSet::new(),
+            consumers: HashMap::new(),
+            history_listeners: HashSet::new(),
+            observers: HashSet::new(),
+            recorders: HashSet::new(),
+            history: NotificationHistory::new(),
+            tagged: HashMap::new(),
         }
     }
 
-    fn update(&mut self, _msg: Self::Message) {}
+    fn update(&mut self, msg: Self::Message) {
+        let outcome = match msg.kind {
+            NotificationEventKind::Closed => Some(NotificationOutcome::Closed),
+            NotificationEventKind::TimedOut => Some(NotificationOutcome::TimedOut),
+            _ => None,
+        };
+        if let Some(outcome) = outcome {
+            self.history.set_outcome(msg.id, outcome);
+            self.send_history();
+            if let Some(ref tag) = msg.tag {
+                self.tagged.remove(tag);
+            }
+        }
+
+        self.send_event(msg.id, msg.tag, msg.kind);
+        if let Some(callback) = msg.callback {
+            callback.emit(());
+        }
+    }
 
     fn handle_input(&mut self, msg: Self::Input, id: HandlerId) {
         use NotificationAgentInput::*;
         match msg {
             RegisterConsumer => {
-                self.consumers.insert(id);
+                self.consumers.insert(id, None);
             }
-            New(props) => {
-                let mut props: NotificationProps = props; // TODO: type?
-                props.standalone = false;
-                self.consumers.iter().for_each(|&id| {
-                    self.link
-                        .respond(id, NotificationAgentOutput::New(props.clone()))
-                })
+            RegisterScopedConsumer(scope) => {
+                self.consumers.insert(id, Some(scope));
             }
-            NewTagged(props, tag) => {
-                let mut props: NotificationProps = props;
-                props.standalone = false;
-                self.consumers.iter().for_each(|&id| {
-                    self.link.respond(
-                        id,
-                        NotificationAgentOutput::NewTagged(props.clone(), tag.clone()),
-                    )
-                })
+            New(props) => self.spawn(props, None, None),
+            NewTagged(props, tag) => self.spawn(props, Some(tag), None),
+            NewInScope(props, scope) => self.spawn(props, None, Some(scope)),
+            NewTaggedInScope(props, tag, scope) => self.spawn(props, Some(tag), Some(scope)),
+            UpdateTagged(props, tag) => {
+                // Nothing to update if the notification has already left the screen
+                if let Some(&id) = self.tagged.get(&tag) {
+                    let mut props: NotificationProps = props;
+                    props.standalone = false;
+                    self.history.update(id, &props);
+                    self.send_history();
+                    self.track(id, Some(tag.clone()), &mut props);
+                    self.consumers
+                        .keys()
+                        .chain(&self.recorders)
+                        .for_each(|&id| {
+                            self.link.respond(
+                                id,
+                                NotificationAgentOutput::UpdateTagged(props.clone(), tag.clone()),
+                            )
+                        });
+                    self.send_event(id, Some(tag), NotificationEventKind::Updated);
+                }
             }
-            CloseTagged(tag) => self.consumers.iter().for_each(|&id| {
+            CloseTagged(tag) => {
+                self.consumers
+                    .keys()
+                    .chain(&self.recorders)
+                    .for_each(|&id| {
+                        self.link
+                            .respond(id, NotificationAgentOutput::CloseTagged(tag.clone()))
+                    });
+                // Consumers remove the notification without its callbacks
+                // firing, so record the outcome here
+                if let Some(id) = self.tagged.remove(&tag) {
+                    self.history.set_outcome(id, NotificationOutcome::Closed);
+                    self.send_history();
+                    self.send_event(id, Some(tag), NotificationEventKind::Closed);
+                }
+            }
+
+            RegisterHistory => {
+                self.history_listeners.insert(id);
                 self.link
-                    .respond(id, NotificationAgentOutput::CloseTagged(tag.clone()))
-            }),
+                    .respond(id, NotificationAgentOutput::History(self.history.entries()));
+            }
+            MarkHistoryRead => {
+                self.history.mark_read();
+                self.send_history();
+            }
+            ClearHistory => {
+                self.history.clear();
+                self.send_history();
+            }
+
+            RegisterObserver => {
+                self.observers.insert(id);
+            }
+            RegisterRecorder => {
+                self.recorders.insert(id);
+            }
         };
     }
 
@@ -75,5 +188,130 @@ impl Agent for NotificationAgent {
 
     fn disconnected(&mut self, id: HandlerId) {
         self.consumers.remove(&id);
+        self.history_listeners.remove(&id);
+        self.observers.remove(&id);
+        self.recorders.remove(&id);
+    }
+}
+
+impl NotificationAgent {
+    fn spawn(&mut self, props: NotificationProps, tag: Option<String>, scope: Option<String>) {
+        let mut props: NotificationProps = props; // TODO: type?
+        props.standalone = false;
+        // A tag that is still displayed is replaced in place by the consumers,
+        // so it keeps its history entry
+        let displayed = tag.as_ref().and_then(|tag| self.tagged.get(tag).copied());
+        let id = match displayed {
+            Some(id) => {
+                self.history.update(id, &props);
+                id
+            }
+            None => self.history.record(&props, tag.clone()),
+        };
+        self.send_history();
+        if let Some(ref tag) = tag {
+            self.tagged.insert(tag.clone(), id);
+        }
+        self.track(id, tag.clone(), &mut props);
+
+        let targets = self.targets(scope.as_deref());
+        for consumer in targets.into_iter().chain(self.recorders.iter().copied()) {
+            let output = match tag {
+                Some(ref tag) => NotificationAgentOutput::NewTagged(props.clone(), tag.clone()),
+                None => NotificationAgentOutput::New(props.clone()),
+            };
+            self.link.respond(consumer, output);
+        }
+        let kind = if displayed.is_some() {
+            NotificationEventKind::Updated
+        } else {
+            NotificationEventKind::Spawned
+        };
+        self.send_event(id, tag, kind);
+    }
+
+    /// Consumers registered with the given scope, falling back to the default
+    /// consumers if there are none (or no scope was given)
+    fn targets(&self, scope: Option<&str>) -> Vec<HandlerId> {
+        let targets = self.consumers_in(scope);
+        if targets.is_empty() && scope.is_some() {
+            self.consumers_in(None)
+        } else {
+            targets
+        }
+    }
+
+    fn consumers_in(&self, scope: Option<&str>) -> Vec<HandlerId> {
+        self.consumers
+            .iter()
+            .filter(|(_, consumer_scope)| consumer_scope.as_deref() == scope)
+            .map(|(&id, _)| id)
+            .collect()
+    }
+
+    /// Wrap the notification's callbacks, so the agent learns how the user
+    /// interacts with it
+    fn track(&self, id: usize, tag: Option<String>, props: &mut NotificationProps) {
+        props.on_shown = Some(self.wrap(
+            id,
+            tag.clone(),
+            NotificationEventKind::Shown,
+            props.on_shown.take(),
+        ));
+        props.on_closed = Some(self.wrap(
+            id,
+            tag.clone(),
+            NotificationEventKind::Closed,
+            props.on_closed.take(),
+        ));
+        props.on_timeout = Some(self.wrap(
+            id,
+            tag.clone(),
+            NotificationEventKind::TimedOut,
+            props.on_timeout.take(),
+        ));
+        for action in props.actions.iter_mut() {
+            action.callback = self.wrap(
+                id,
+                tag.clone(),
+                NotificationEventKind::ActionClicked(action.label.clone()),
+                Some(action.callback.clone()),
+            );
+        }
+    }
+
+    fn wrap(
+        &self,
+        id: usize,
+        tag: Option<String>,
+        kind: NotificationEventKind,
+        callback: Option<Callback<()>>,
+    ) -> Callback<()> {
+        self.link.callback(move |_| Msg {
+            id,
+            tag: tag.clone(),
+            kind: kind.clone(),
+            callback: callback.clone(),
+        })
+    }
+
+    fn send_history(&self) {
+        self.history_listeners.iter().for_each(|&id| {
+            self.link
+                .respond(id, NotificationAgentOutput::History(self.history.entries()))
+        })
+    }
+
+    fn send_event(&self, id: usize, tag: Option<String>, kind: NotificationEventKind) {
+        let event = NotificationEvent {
+            id,
+            tag,
+            kind,
+            timestamp: js_sys::Date::now(),
+        };
+        self.observers.iter().for_each(|&id| {
+            self.link
+                .respond(id, NotificationAgentOutput::Event(event.clone()))
+        })
     }
 }
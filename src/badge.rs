@@ -0,0 +1,108 @@
+use yew::prelude::*;
+
+use crate::history::HistoryEntry;
+use crate::i18n::{self, Label};
+use crate::state::NotificationOutcome;
+use crate::theme::{self, Part};
+use crate::{NotificationAgent, NotificationAgentInput, NotificationAgentOutput};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct NotificationBadgeProps {
+    /// Content of the badge button. Defaults to a bell glyph
+    #[prop_or(html! {"\u{1F514}"})]
+    pub icon: Html,
+
+    #[prop_or_default]
+    pub onclick: Option<Callback<()>>,
+}
+
+/// A button showing how many notifications timed out before the user
+/// interacted with them. The count resets once the history is opened in a
+/// [`NotificationCenter`](crate::NotificationCenter)
+pub struct NotificationBadge {
+    missed: usize,
+
+    props: NotificationBadgeProps,
+    link: ComponentLink<Self>,
+    _bridge: Box<dyn Bridge<NotificationAgent>>,
+}
+
+pub enum Msg {
+    ServiceMsg(NotificationAgentOutput),
+    Clicked,
+}
+
+impl Component for NotificationBadge {
+    type Message = Msg;
+    type Properties = NotificationBadgeProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut bridge = NotificationAgent::bridge(link.callback(Msg::ServiceMsg));
+        bridge.send(NotificationAgentInput::RegisterHistory);
+        Self {
+            missed: 0,
+            props,
+            link,
+            _bridge: bridge,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ServiceMsg(NotificationAgentOutput::History(entries)) => {
+                let missed = entries
+                    .iter()
+                    .filter(|entry| Self::is_missed(entry))
+                    .count();
+                let changed = missed != self.missed;
+                self.missed = missed;
+                changed
+            }
+            Msg::ServiceMsg(_) => false,
+            Msg::Clicked => {
+                if let Some(ref onclick) = self.props.onclick {
+                    onclick.emit(())
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let theme = theme::theme();
+        let count = if self.missed > 0 {
+            let mut cls = theme.part(Part::Count);
+            cls.push("ybn-badge-count");
+            html! { <span class={cls}>{self.missed}</span> }
+        } else {
+            html! {}
+        };
+
+        let label = i18n::label_with(Label::MissedNotifications, &[("count", &self.missed)]);
+
+        let mut cls = theme.part(Part::BadgeButton);
+        cls.push("ybn-badge");
+
+        html! {
+            <button class={cls} aria-label={label} onclick={self.link.callback(|_| Msg::Clicked)}>
+                <span class={theme.part(Part::Icon)}>{self.props.icon.clone()}</span>
+                {count}
+            </button>
+        }
+    }
+}
+
+impl NotificationBadge {
+    fn is_missed(entry: &HistoryEntry) -> bool {
+        !entry.read && entry.outcome == Some(NotificationOutcome::TimedOut)
+    }
+}
@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use yew::prelude::*;
+
+use crate::{Color, Direction, Icon, NotificationAction, NotificationProps, Position, Size};
+
+/// Builds [`NotificationProps`] from plain strings or [`Html`], leaving the
+/// fields that aren't set to the consumer's
+/// [`NotificationConfig`](crate::NotificationConfig)
+///
+/// ```ignore
+/// service.spawn(
+///     NotificationBuilder::new()
+///         .header("Saved")
+///         .body("Your changes were saved")
+///         .color(Color::Success)
+///         .build(),
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct NotificationBuilder {
+    header: Option<String>,
+    icon: Option<Icon>,
+    body: Vec<Html>,
+    details: Option<String>,
+    max_body_height: Option<String>,
+    color: Option<Color>,
+    size: Option<Size>,
+    position: Option<Position>,
+    timeout: Option<Duration>,
+    sticky: bool,
+    can_close: Option<bool>,
+    on_timeout: Option<Callback<()>>,
+    on_closed: Option<Callback<()>>,
+    on_shown: Option<Callback<()>>,
+    actions: Vec<NotificationAction>,
+}
+
+impl NotificationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header(mut self, header: impl Into<String>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Add to the body of the notification. Called more than once, the parts
+    /// are rendered one after the other
+    pub fn body(mut self, body: impl Into<Html>) -> Self {
+        self.body.push(body.into());
+        self
+    }
+
+    /// Add markdown text to the body, rendered with
+    /// [`render_markdown`](crate::render_markdown)
+    #[cfg(feature = "markdown")]
+    pub fn markdown(self, text: &str) -> Self {
+        self.body(crate::render_markdown(text))
+    }
+
+    /// Longer text shown in a collapsed section that can be copied
+    pub fn details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Cut the body off at this height (a CSS length), with a toggle to
+    /// show all of it
+    pub fn max_body_height(mut self, max_body_height: impl Into<String>) -> Self {
+        self.max_body_height = Some(max_body_height.into());
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self.sticky = false;
+        self
+    }
+
+    /// Never time out, even if the consumer's config has a default timeout
+    pub fn sticky(mut self) -> Self {
+        self.timeout = None;
+        self.sticky = true;
+        self
+    }
+
+    pub fn can_close(mut self, can_close: bool) -> Self {
+        self.can_close = Some(can_close);
+        self
+    }
+
+    pub fn on_timeout(mut self, callback: Callback<()>) -> Self {
+        self.on_timeout = Some(callback);
+        self
+    }
+
+    pub fn on_closed(mut self, callback: Callback<()>) -> Self {
+        self.on_closed = Some(callback);
+        self
+    }
+
+    pub fn on_shown(mut self, callback: Callback<()>) -> Self {
+        self.on_shown = Some(callback);
+        self
+    }
+
+    pub fn action(mut self, label: impl Into<String>, callback: Callback<()>) -> Self {
+        self.actions.push(NotificationAction::new(label, callback));
+        self
+    }
+
+    pub fn build(self) -> NotificationProps {
+        NotificationProps {
+            header: self.header,
+            icon: self.icon,
+            children: Children::new(self.body),
+            details: self.details,
+            max_body_height: self.max_body_height,
+            can_close: self.can_close.unwrap_or(true),
+            timeout: self.timeout,
+            sticky: self.sticky,
+            on_timeout: self.on_timeout,
+            on_closed: self.on_closed,
+            on_shown: self.on_shown,
+            actions: self.actions,
+            color: self.color.unwrap_or(Color::Default),
+            size: self.size,
+            position: self.position,
+            direction: Direction::default(),
+            clock: Default::default(),
+            standalone: true,
+        }
+    }
+}
+
+impl From<NotificationBuilder> for NotificationProps {
+    fn from(builder: NotificationBuilder) -> Self {
+        builder.build()
+    }
+}
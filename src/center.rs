@@ -0,0 +1,175 @@
+use yew::prelude::*;
+
+use crate::history::HistoryEntry;
+use crate::i18n::{self, Label};
+use crate::theme::{self, Part};
+use crate::{NotificationAgent, NotificationAgentInput, NotificationAgentOutput, Size};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct NotificationCenterProps {
+    /// Text of the button that opens the history. Defaults to
+    /// [`Label::Notifications`]
+    #[prop_or_default]
+    pub label: Option<String>,
+
+    /// Align the dropdown menu to the right of the button
+    #[prop_or(true)]
+    pub right: bool,
+}
+
+/// A bulma [dropdown](https://bulma.io/documentation/components/dropdown/)
+/// listing every notification that has been spawned through the
+/// [`NotificationService`](crate::NotificationService), including ones that
+/// have already closed or timed out
+pub struct NotificationCenter {
+    open: bool,
+    entries: Vec<HistoryEntry>,
+
+    props: NotificationCenterProps,
+    link: ComponentLink<Self>,
+    bridge: Box<dyn Bridge<NotificationAgent>>,
+}
+
+pub enum Msg {
+    ServiceMsg(NotificationAgentOutput),
+    Toggle,
+    ClearAll,
+}
+
+impl Component for NotificationCenter {
+    type Message = Msg;
+    type Properties = NotificationCenterProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut bridge = NotificationAgent::bridge(link.callback(Msg::ServiceMsg));
+        bridge.send(NotificationAgentInput::RegisterHistory);
+        Self {
+            open: false,
+            entries: Vec::new(),
+            props,
+            link,
+            bridge,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ServiceMsg(NotificationAgentOutput::History(entries)) => {
+                self.entries = entries;
+                true
+            }
+            Msg::ServiceMsg(_) => false,
+            Msg::Toggle => {
+                self.open = !self.open;
+                // Unread entries stay highlighted while the dropdown is open,
+                // including ones arriving meanwhile, and count as seen once it
+                // closes
+                if !self.open && self.entries.iter().any(|entry| !entry.read) {
+                    self.bridge.send(NotificationAgentInput::MarkHistoryRead);
+                }
+                true
+            }
+            Msg::ClearAll => {
+                self.bridge.send(NotificationAgentInput::ClearHistory);
+                false
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let theme = theme::theme();
+        let mut cls = theme.part(Part::Dropdown);
+        cls.push("ybn-center");
+        if self.open {
+            cls.push(theme.part(Part::DropdownActive));
+        }
+        if self.props.right {
+            cls.push(theme.part(Part::DropdownRight));
+        }
+
+        let unread = self.entries.iter().filter(|entry| !entry.read).count();
+        let badge = if unread > 0 {
+            let mut cls = theme.part(Part::Count);
+            cls.push("ybn-center-count");
+            html! { <span class={cls}>{unread}</span> }
+        } else {
+            html! {}
+        };
+
+        let label = self
+            .props
+            .label
+            .clone()
+            .unwrap_or_else(|| i18n::label(Label::Notifications));
+
+        let items = if self.entries.is_empty() {
+            html! { <div class={theme.part(Part::DropdownItem)}>{i18n::label(Label::NoNotifications)}</div> }
+        } else {
+            // Newest first
+            self.entries
+                .iter()
+                .rev()
+                .map(Self::view_entry)
+                .collect::<Html>()
+        };
+
+        let mut content_cls = theme.part(Part::DropdownContent);
+        content_cls.push("ybn-center-content");
+
+        html! {
+            <div class={cls}>
+                <div class={theme.part(Part::DropdownTrigger)}>
+                    <button class={theme.part(Part::DropdownButton)} aria-haspopup="true" onclick={self.link.callback(|_| Msg::Toggle)}>
+                        <span>{label}</span>
+                        {badge}
+                    </button>
+                </div>
+                <div class={theme.part(Part::DropdownMenu)} role="menu">
+                    <div class={content_cls}>
+                        {items}
+                        <hr class={theme.part(Part::DropdownDivider)} />
+                        <a class={theme.part(Part::DropdownItem)} onclick={self.link.callback(|_| Msg::ClearAll)}>{i18n::label(Label::ClearAll)}</a>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl NotificationCenter {
+    fn view_entry(entry: &HistoryEntry) -> Html {
+        let theme = theme::theme();
+        let mut cls = theme.part(Part::Message);
+        cls.push(theme.color(&entry.color));
+        if let Some(class) = entry.color.custom_class() {
+            cls.push(class.to_owned());
+        }
+        cls.push(theme.size(Size::Small));
+        if !entry.read {
+            cls.push("ybn-unread");
+        }
+
+        html! {
+            <div class={theme.part(Part::DropdownItem)} key={entry.id}>
+                <article class={cls}>
+                    <div class={theme.part(Part::MessageHeader)} style={entry.color.header_style()}>
+                        <p>{entry.header.clone().unwrap_or_default()}</p>
+                        <time>{entry.time_string()}</time>
+                    </div>
+                    <div class={theme.part(Part::MessageBody)} style={entry.color.body_style()}>
+                        {entry.children.clone()}
+                    </div>
+                </article>
+            </div>
+        }
+    }
+}
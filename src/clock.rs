@@ -0,0 +1,303 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+use yew::Callback;
+use yew_services::timeout::TimeoutService;
+
+/// Source of time and timers for notifications
+pub trait Clock {
+    /// Time since an arbitrary epoch
+    fn now(&self) -> Duration;
+
+    /// Call `callback` once `delay` has elapsed. Dropping the returned task
+    /// cancels the timer
+    fn schedule(&self, delay: Duration, callback: Box<dyn FnOnce()>) -> TimerTask;
+}
+
+/// A scheduled timer, cancelled when dropped
+pub struct TimerTask {
+    // Only held as a drop guard, never read
+    #[allow(dead_code)]
+    inner: Box<dyn Any>,
+}
+
+impl TimerTask {
+    /// Wrap a value that cancels the timer when it is dropped
+    pub fn new(inner: impl Any) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+}
+
+impl fmt::Debug for TimerTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TimerTask")
+    }
+}
+
+/// Shared handle to a [`Clock`], passed to the components as a property.
+/// Defaults to a [`BrowserClock`]
+#[derive(Clone)]
+pub struct ClockHandle(Rc<dyn Clock>);
+
+impl ClockHandle {
+    pub fn new(clock: impl Clock + 'static) -> Self {
+        Self(Rc::new(clock))
+    }
+
+    pub fn now(&self) -> Duration {
+        self.0.now()
+    }
+
+    pub fn schedule(&self, delay: Duration, callback: Box<dyn FnOnce()>) -> TimerTask {
+        self.0.schedule(delay, callback)
+    }
+}
+
+thread_local! {
+    /// Shared by all default handles, so that they compare equal
+    static BROWSER_CLOCK: ClockHandle = ClockHandle::new(BrowserClock);
+}
+
+impl Default for ClockHandle {
+    fn default() -> Self {
+        BROWSER_CLOCK.with(Clone::clone)
+    }
+}
+
+impl PartialEq for ClockHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for ClockHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ClockHandle")
+    }
+}
+
+impl From<ManualClock> for ClockHandle {
+    fn from(clock: ManualClock) -> Self {
+        Self::new(clock)
+    }
+}
+
+/// The browser's clock, with timers backed by `setTimeout`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BrowserClock;
+
+impl Clock for BrowserClock {
+    fn now(&self) -> Duration {
+        Duration::from_millis(js_sys::Date::now() as u64)
+    }
+
+    fn schedule(&self, delay: Duration, callback: Box<dyn FnOnce()>) -> TimerTask {
+        TimerTask::new(TimeoutService::spawn(
+            delay,
+            Callback::once(move |_| callback()),
+        ))
+    }
+}
+
+/// A clock that only moves when told to, for deterministic tests of
+/// timeout behaviour. Clones share the same time and timers
+///
+/// ```ignore
+/// let clock = ManualClock::new();
+/// html! { <NotificationConsumer clock={ClockHandle::from(clock.clone())} /> };
+/// // ... spawn a notification with a 5 second timeout
+/// clock.advance(Duration::from_secs(5));
+/// ```
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    state: Rc<RefCell<ManualClockState>>,
+}
+
+#[derive(Default)]
+struct ManualClockState {
+    now: Duration,
+    next_id: usize,
+    timers: Vec<PendingTimer>,
+}
+
+struct PendingTimer {
+    id: usize,
+    deadline: Duration,
+    callback: Box<dyn FnOnce()>,
+}
+
+/// Removes its timer from the [`ManualClock`] when dropped
+struct ManualTimer {
+    id: usize,
+    state: Weak<RefCell<ManualClockState>>,
+}
+
+impl Drop for ManualTimer {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.upgrade() {
+            if let Ok(mut state) = state.try_borrow_mut() {
+                state.timers.retain(|timer| timer.id != self.id);
+            }
+        }
+    }
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of timers that have not fired or been cancelled
+    pub fn pending(&self) -> usize {
+        self.state.borrow().timers.len()
+    }
+
+    /// Move the clock forward, firing due timers one at a time in the order
+    /// they are due. Timers scheduled by a callback fire in the same call if
+    /// they fall due before the new time
+    pub fn advance(&self, by: Duration) {
+        let target = self.state.borrow().now + by;
+        loop {
+            let due = {
+                let mut state = self.state.borrow_mut();
+                let next = state
+                    .timers
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, timer)| timer.deadline <= target)
+                    .min_by_key(|(_, timer)| timer.deadline)
+                    .map(|(index, _)| index);
+                next.map(|index| {
+                    let timer = state.timers.remove(index);
+                    state.now = timer.deadline;
+                    timer.callback
+                })
+            };
+            // Not borrowed while the callback runs, so it can schedule more timers
+            match due {
+                Some(callback) => callback(),
+                None => break,
+            }
+        }
+        self.state.borrow_mut().now = target;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.state.borrow().now
+    }
+
+    fn schedule(&self, delay: Duration, callback: Box<dyn FnOnce()>) -> TimerTask {
+        let mut state = self.state.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+        let deadline = state.now + delay;
+        state.timers.push(PendingTimer {
+            id,
+            deadline,
+            callback,
+        });
+        TimerTask::new(ManualTimer {
+            id,
+            state: Rc::downgrade(&self.state),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    /// Schedule a timer that records `name` when it fires
+    fn record(
+        clock: &ManualClock,
+        fired: &Rc<RefCell<Vec<&'static str>>>,
+        delay: Duration,
+        name: &'static str,
+    ) -> TimerTask {
+        let fired = fired.clone();
+        clock.schedule(delay, Box::new(move || fired.borrow_mut().push(name)))
+    }
+
+    #[test]
+    fn default_handles_are_equal() {
+        assert_eq!(ClockHandle::default(), ClockHandle::default());
+        assert_ne!(
+            ClockHandle::default(),
+            ClockHandle::from(ManualClock::new())
+        );
+    }
+
+    #[test]
+    fn advance_fires_due_timers_in_order() {
+        let clock = ManualClock::new();
+        let fired = Rc::new(RefCell::new(Vec::new()));
+        let _late = record(&clock, &fired, ms(300), "late");
+        let _early = record(&clock, &fired, ms(100), "early");
+        let _tie = record(&clock, &fired, ms(100), "tie");
+
+        clock.advance(ms(99));
+        assert!(fired.borrow().is_empty());
+
+        clock.advance(ms(201));
+        assert_eq!(*fired.borrow(), vec!["early", "tie", "late"]);
+        assert_eq!(clock.now(), ms(300));
+        assert_eq!(clock.pending(), 0);
+    }
+
+    #[test]
+    fn advance_fires_timers_scheduled_by_callbacks() {
+        let clock = ManualClock::new();
+        let fired = Rc::new(RefCell::new(Vec::new()));
+        let tasks = Rc::new(RefCell::new(Vec::new()));
+        let _first = {
+            let (clock, fired, tasks) = (clock.clone(), fired.clone(), tasks.clone());
+            clock.clone().schedule(
+                ms(100),
+                Box::new(move || {
+                    fired.borrow_mut().push("first");
+                    // Due at 150ms, within the same advance
+                    tasks
+                        .borrow_mut()
+                        .push(record(&clock, &fired, ms(50), "second"));
+                    // Due at 1100ms, after it
+                    tasks
+                        .borrow_mut()
+                        .push(record(&clock, &fired, ms(1000), "third"));
+                }),
+            )
+        };
+
+        clock.advance(ms(500));
+        assert_eq!(*fired.borrow(), vec!["first", "second"]);
+        assert_eq!(clock.pending(), 1);
+
+        clock.advance(ms(600));
+        assert_eq!(*fired.borrow(), vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn dropping_task_cancels_timer() {
+        let clock = ManualClock::new();
+        let fired = Rc::new(RefCell::new(Vec::new()));
+        let _kept = record(&clock, &fired, ms(100), "kept");
+        let dropped = record(&clock, &fired, ms(100), "dropped");
+        assert_eq!(clock.pending(), 2);
+
+        drop(dropped);
+        assert_eq!(clock.pending(), 1);
+
+        clock.advance(ms(100));
+        assert_eq!(*fired.borrow(), vec!["kept"]);
+    }
+}
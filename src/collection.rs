@@ -0,0 +1,165 @@
+use yew::prelude::*;
+
+use crate::clock::ClockHandle;
+use crate::config::NotificationConfig;
+use crate::state::NotificationStack;
+use crate::{Direction, Notification, NotificationProps, Position};
+
+/// Message constructor for a notification's wrapped closed or timeout callback
+pub(crate) type WrapFn<COMP> = fn(usize, Option<Callback<()>>) -> <COMP as Component>::Message;
+
+/// Helper struct for rendering a [`NotificationStack`] of notifications
+pub(crate) struct NotificationCollection {
+    stack: NotificationStack<NotificationProps>,
+    clock: ClockHandle,
+    config: NotificationConfig,
+    direction: Direction,
+}
+
+impl NotificationCollection {
+    pub(crate) fn new(
+        max_visible: Option<usize>,
+        clock: ClockHandle,
+        config: NotificationConfig,
+        direction: Direction,
+    ) -> Self {
+        #[cfg(feature = "inject-styles")]
+        config.styles().inject();
+        Self {
+            stack: NotificationStack::with_max_visible(max_visible),
+            clock,
+            config,
+            direction,
+        }
+    }
+
+    /// Clock passed on to notifications spawned from now on
+    pub(crate) fn set_clock(&mut self, clock: ClockHandle) {
+        self.clock = clock;
+    }
+
+    /// Defaults applied to notifications spawned from now on
+    pub(crate) fn set_config(&mut self, config: NotificationConfig) {
+        #[cfg(feature = "inject-styles")]
+        if config.styles() != self.config.styles() {
+            config.styles().inject();
+        }
+        self.config = config;
+    }
+
+    /// Direction of notifications spawned from now on
+    pub(crate) fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    pub(crate) fn set_max_visible(&mut self, max_visible: Option<usize>) {
+        self.stack.set_max_visible(max_visible);
+    }
+
+    /// Add a notification, wrapping its closed and timeout callbacks in
+    /// messages for the rendering component, so it can control rendering.
+    /// A notification with the tag of an existing one replaces it in place.
+    /// Fields the notification doesn't set are filled in from the config
+    pub(crate) fn spawn<COMP: Component>(
+        &mut self,
+        link: &ComponentLink<COMP>,
+        mut props: NotificationProps,
+        tag: Option<String>,
+        closed: WrapFn<COMP>,
+        timed_out: WrapFn<COMP>,
+    ) {
+        if let Some(entry) = tag.as_deref().and_then(|tag| self.stack.find_tag(tag)) {
+            props.position = Some(entry.position);
+        }
+        props.clock = self.clock.clone();
+        props.direction = self.direction;
+        self.config.apply(&mut props);
+        let position = props.position();
+        self.stack.push_with(position, tag, |id| {
+            Self::wrap(link, props, id, closed, timed_out)
+        });
+    }
+
+    /// Replace the properties of the notification with the given tag. The
+    /// notification keeps its position and id, so that it isn't re-rendered
+    /// from scratch
+    pub(crate) fn update_tag<COMP: Component>(
+        &mut self,
+        link: &ComponentLink<COMP>,
+        mut props: NotificationProps,
+        tag: &str,
+        closed: WrapFn<COMP>,
+        timed_out: WrapFn<COMP>,
+    ) {
+        if let Some(entry) = self.stack.find_tag(tag) {
+            props.position = Some(entry.position);
+            props.clock = self.clock.clone();
+            props.direction = self.direction;
+            self.config.apply(&mut props);
+            self.stack
+                .replace_tag_with(tag, |id| Self::wrap(link, props, id, closed, timed_out));
+        }
+    }
+
+    pub(crate) fn remove(&mut self, id: usize) {
+        self.stack.remove(id);
+    }
+
+    pub(crate) fn remove_tag(&mut self, tag: &str) {
+        self.stack.remove_tag(tag);
+    }
+
+    /// Render the notifications stacked in the corners of the screen, or in
+    /// a single element if `inline` is set
+    pub(crate) fn view(&self, inline: bool) -> Html {
+        if inline {
+            let mut visible = self.stack.visible().peekable();
+            if visible.peek().is_none() {
+                html! {}
+            } else {
+                html! {
+                    <div class="ybn-inline">
+                        { visible.map(|entry| Self::view_item(entry.id, &entry.payload)).collect::<Html>() }
+                    </div>
+                }
+            }
+        } else {
+            Position::ALL
+                .iter()
+                .map(|&position| self.view_position(position))
+                .collect::<Html>()
+        }
+    }
+
+    fn view_position(&self, position: Position) -> Html {
+        let mut visible = self.stack.visible_at(position).peekable();
+        if visible.peek().is_none() {
+            html! {}
+        } else {
+            html! {
+                <div class={position.style()}>
+                    { visible.map(|entry| Self::view_item(entry.id, &entry.payload)).collect::<Html>() }
+                </div>
+            }
+        }
+    }
+
+    fn view_item(id: usize, props: &NotificationProps) -> Html {
+        let props = props.clone();
+        html! { <Notification key={id} with props /> }
+    }
+
+    fn wrap<COMP: Component>(
+        link: &ComponentLink<COMP>,
+        mut props: NotificationProps,
+        id: usize,
+        closed: WrapFn<COMP>,
+        timed_out: WrapFn<COMP>,
+    ) -> NotificationProps {
+        let callback = props.on_closed.take();
+        props.on_closed = Some(link.callback_once(move |_| closed(id, callback)));
+        let callback = props.on_timeout.take();
+        props.on_timeout = Some(link.callback_once(move |_| timed_out(id, callback)));
+        props
+    }
+}
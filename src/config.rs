@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[cfg(feature = "inject-styles")]
+use crate::StyleConfig;
+use crate::{Color, IconSet, NotificationProps, Position, Size};
+
+/// Values used for the fields a notification doesn't set itself
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct NotificationDefaults {
+    pub position: Option<Position>,
+    pub size: Option<Size>,
+    pub timeout: Option<Duration>,
+    /// Never time out, overriding a timeout from a less specific default
+    pub sticky: bool,
+}
+
+impl NotificationDefaults {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self.sticky = false;
+        self
+    }
+
+    pub fn sticky(mut self) -> Self {
+        self.timeout = None;
+        self.sticky = true;
+        self
+    }
+}
+
+/// Defaults for the notifications rendered by a
+/// [`NotificationConsumer`](crate::NotificationConsumer) or
+/// [`NotificationProvider`](crate::NotificationProvider), per [`Color`]. A
+/// field explicitly set on a notification always wins, then the defaults for
+/// its color, then the defaults for all colors
+///
+/// ```ignore
+/// let config = NotificationConfig::new()
+///     .with_defaults(NotificationDefaults::new().position(Position::BottomRight))
+///     .with_color(
+///         Color::Success,
+///         NotificationDefaults::new()
+///             .position(Position::TopRight)
+///             .timeout(Duration::from_secs(4)),
+///     )
+///     .with_color(Color::Danger, NotificationDefaults::new().sticky());
+/// html! { <NotificationConsumer config={config} /> }
+/// ```
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct NotificationConfig {
+    defaults: NotificationDefaults,
+    colors: HashMap<Color, NotificationDefaults>,
+    icons: Option<IconSet>,
+    #[cfg(feature = "inject-styles")]
+    styles: StyleConfig,
+}
+
+impl NotificationConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defaults for notifications of any color
+    pub fn with_defaults(mut self, defaults: NotificationDefaults) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Defaults for notifications of the given color
+    pub fn with_color(mut self, color: Color, defaults: NotificationDefaults) -> Self {
+        self.colors.insert(color, defaults);
+        self
+    }
+
+    /// Show the icon for their color from the given set on notifications
+    /// that don't have an icon
+    pub fn with_icons(mut self, icons: IconSet) -> Self {
+        self.icons = Some(icons);
+        self
+    }
+
+    /// Sizes and animation durations of the styles injected into the document
+    #[cfg(feature = "inject-styles")]
+    pub fn with_styles(mut self, styles: StyleConfig) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    #[cfg(feature = "inject-styles")]
+    pub(crate) fn styles(&self) -> &StyleConfig {
+        &self.styles
+    }
+
+    /// Fill in the fields the notification doesn't set
+    pub(crate) fn apply(&self, props: &mut NotificationProps) {
+        let color = props.color.clone();
+        let layers = || {
+            self.colors
+                .get(&color)
+                .into_iter()
+                .chain(std::iter::once(&self.defaults))
+        };
+        if props.position.is_none() {
+            props.position = layers().find_map(|defaults| defaults.position);
+        }
+        if props.icon.is_none() {
+            props.icon = self.icons.map(|icons| icons.icon(&color));
+        }
+        if props.size.is_none() {
+            props.size = layers().find_map(|defaults| defaults.size);
+        }
+        if !props.sticky && props.timeout.is_none() {
+            if let Some(defaults) =
+                layers().find(|defaults| defaults.sticky || defaults.timeout.is_some())
+            {
+                props.sticky = defaults.sticky;
+                props.timeout = defaults.timeout;
+            }
+        }
+    }
+}
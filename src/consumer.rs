@@ -1,80 +1,127 @@
 use yew::prelude::*;
 
-use crate::{
-    Notification, NotificationAgent, NotificationAgentInput, NotificationAgentOutput,
-    NotificationProps, Position,
-};
+use crate::clock::ClockHandle;
+use crate::collection::NotificationCollection;
+use crate::config::NotificationConfig;
+use crate::{Direction, NotificationAgent, NotificationAgentInput, NotificationAgentOutput};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct NotificationConsumerProps {
+    /// Only render notifications spawned in this scope (e.g. with
+    /// [`NotificationService::spawn_in_scope`](crate::NotificationService::spawn_in_scope)).
+    /// Consumers without a scope render all other notifications
+    #[prop_or_default]
+    pub scope: Option<String>,
+
+    /// Render the notifications in place, inside the consumer's parent
+    /// element, rather than stacked in the corners of the screen. The
+    /// `position` of inline notifications only determines the direction they
+    /// animate in from
+    #[prop_or_default]
+    pub inline: bool,
+
+    /// Maximum number of notifications displayed at each position. Further
+    /// notifications are queued until there is room
+    #[prop_or_default]
+    pub max_visible: Option<usize>,
+
+    /// Clock driving the notifications' timeouts. Replace it with a
+    /// [`ManualClock`](crate::clock::ManualClock) to control time in tests
+    #[prop_or_default]
+    pub clock: ClockHandle,
+
+    /// Defaults for the position, size and timeout of notifications that
+    /// don't set them
+    #[prop_or_default]
+    pub config: NotificationConfig,
+
+    /// Layout direction. In a right-to-left layout, logical positions such
+    /// as [`Position::TopStart`](crate::Position::TopStart) are mirrored and
+    /// the close button moves to the left
+    #[prop_or_default]
+    pub direction: Direction,
+}
 
 pub struct NotificationConsumer {
+    props: NotificationConsumerProps,
     link: ComponentLink<Self>,
-    _bridge: Box<dyn Bridge<NotificationAgent>>,
+    bridge: Box<dyn Bridge<NotificationAgent>>,
     notifications: NotificationCollection,
 }
 
 pub enum Msg {
     ServiceMsg(NotificationAgentOutput),
 
-    Closed(Position, usize, Option<Callback<()>>),
-    TimedOut(Position, usize, Option<Callback<()>>),
+    Closed(usize, Option<Callback<()>>),
+    TimedOut(usize, Option<Callback<()>>),
 }
 
 impl Component for NotificationConsumer {
     type Message = Msg;
-    type Properties = ();
-
-    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        let mut bridge = NotificationAgent::bridge(link.callback(Msg::ServiceMsg));
-        bridge.send(NotificationAgentInput::RegisterConsumer);
-        Self {
+    type Properties = NotificationConsumerProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let bridge = NotificationAgent::bridge(link.callback(Msg::ServiceMsg));
+        let notifications = NotificationCollection::new(
+            props.max_visible,
+            props.clock.clone(),
+            props.config.clone(),
+            props.direction,
+        );
+        let mut consumer = Self {
+            props,
             link,
-            _bridge: bridge,
-            notifications: NotificationCollection::new(),
-        }
+            bridge,
+            notifications,
+        };
+        consumer.register();
+        consumer
     }
 
-    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
-        false
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+        let reregister = self.props.scope != props.scope;
+        self.notifications.set_max_visible(props.max_visible);
+        self.notifications.set_clock(props.clock.clone());
+        self.notifications.set_config(props.config.clone());
+        self.notifications.set_direction(props.direction);
+        self.props = props;
+        if reregister {
+            self.register();
+        }
+        true
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::ServiceMsg(msg) => match msg {
-                NotificationAgentOutput::New(mut props) => {
-                    let position = props.position;
-                    let id = self.notifications.next_id(position);
-
-                    // Wrap the closed and timeout callbacks in callbacks for
-                    // this component, so we can control rendering
-                    let callback = props.on_closed.take();
-                    props.on_closed = Some(
-                        self.link
-                            .callback_once(move |_| Msg::Closed(position, id, callback)),
-                    );
+                NotificationAgentOutput::New(props) => {
+                    self.notifications
+                        .spawn(&self.link, props, None, Msg::Closed, Msg::TimedOut);
+                    true
+                }
 
-                    let callback = props.on_timeout.take();
-                    props.on_timeout = Some(
-                        self.link
-                            .callback_once(move |_| Msg::TimedOut(position, id, callback)),
+                NotificationAgentOutput::NewTagged(props, tag) => {
+                    self.notifications.spawn(
+                        &self.link,
+                        props,
+                        Some(tag),
+                        Msg::Closed,
+                        Msg::TimedOut,
                     );
-
-                    self.notifications.insert(id, None, position, props);
                     true
                 }
 
-                NotificationAgentOutput::NewTagged(mut props, tag) => {
-                    let position = props.position;
-                    let id = self.notifications.next_id(position);
-                    let callback = props.on_closed.take();
-                    props.on_closed = Some(
-                        self.link
-                            .callback_once(move |_| Msg::Closed(position, id, callback)),
+                NotificationAgentOutput::UpdateTagged(props, tag) => {
+                    self.notifications.update_tag(
+                        &self.link,
+                        props,
+                        &tag,
+                        Msg::Closed,
+                        Msg::TimedOut,
                     );
-                    let callback = props.on_timeout.take();
-                    props.on_timeout = Some(
-                        self.link
-                            .callback_once(move |_| Msg::TimedOut(position, id, callback)),
-                    );
-                    self.notifications.insert(id, Some(tag), position, props);
                     true
                 }
 
@@ -82,18 +129,20 @@ impl Component for NotificationConsumer {
                     self.notifications.remove_tag(&tag);
                     true
                 }
+
+                NotificationAgentOutput::History(_) | NotificationAgentOutput::Event(_) => false,
             },
-            Msg::Closed(position, id, callback) => {
+            Msg::Closed(id, callback) => {
                 yew_services::ConsoleService::log("closed");
-                self.notifications.remove_id(position, id);
+                self.notifications.remove(id);
                 if let Some(callback) = callback {
                     callback.emit(());
                 }
                 true
             }
-            Msg::TimedOut(position, id, callback) => {
+            Msg::TimedOut(id, callback) => {
                 yew_services::ConsoleService::log("timed out");
-                self.notifications.remove_id(position, id);
+                self.notifications.remove(id);
                 if let Some(callback) = callback {
                     callback.emit(());
                 }
@@ -103,150 +152,16 @@ impl Component for NotificationConsumer {
     }
 
     fn view(&self) -> Html {
-        // TODO: conditionally render position divs
-
-        let (top_left, top_right, bottom_left, bottom_right) = self.notifications.by_position();
-
-        html! {
-            <>
-                {top_left.view(Position::TopLeft)}
-                {top_right.view(Position::TopRight)}
-                {bottom_left.view(Position::BottomLeft)}
-                {bottom_right.view(Position::BottomRight)}
-            </>
-        }
+        self.notifications.view(self.props.inline)
     }
 }
 
-/// Helper struct for managing the notifications
-struct NotificationCollection {
-    tl: NotificaitonList,
-    tr: NotificaitonList,
-    bl: NotificaitonList,
-    br: NotificaitonList,
-}
-
-impl NotificationCollection {
-    fn new() -> Self {
-        Self {
-            tl: NotificaitonList::new(),
-            tr: NotificaitonList::new(),
-            bl: NotificaitonList::new(),
-            br: NotificaitonList::new(),
-        }
-    }
-
-    fn next_id(&mut self, position: Position) -> usize {
-        match position {
-            Position::TopLeft => self.tl.next_id(),
-            Position::TopRight => self.tr.next_id(),
-            Position::BottomLeft => self.bl.next_id(),
-            Position::BottomRight => self.br.next_id(),
-        }
-    }
-
-    fn insert(
-        &mut self,
-        id: usize,
-        tag: Option<String>,
-        position: Position,
-        props: NotificationProps,
-    ) {
-        match position {
-            Position::TopLeft => self.tl.insert(id, tag, props),
-            Position::TopRight => self.tr.insert(id, tag, props),
-            Position::BottomLeft => self.bl.insert(id, tag, props),
-            Position::BottomRight => self.br.insert(id, tag, props),
-        }
-    }
-
-    fn remove_id(&mut self, position: Position, id: usize) {
-        match position {
-            Position::TopLeft => self.tl.remove_id(id),
-            Position::TopRight => self.tr.remove_id(id),
-            Position::BottomLeft => self.bl.remove_id(id),
-            Position::BottomRight => self.br.remove_id(id),
-        }
-    }
-
-    fn remove_tag(&mut self, tag: &str) {
-        self.tl.remove_tag(tag);
-        self.tr.remove_tag(tag);
-        self.bl.remove_tag(tag);
-        self.br.remove_tag(tag);
-    }
-
-    /// Vectors of NotificationProps ordered by position
-    /// (TopLeft, TopRight, BottomLeft, BottomRight)
-    fn by_position(
-        &self,
-    ) -> (
-        &NotificaitonList,
-        &NotificaitonList,
-        &NotificaitonList,
-        &NotificaitonList,
-    ) {
-        (&self.tl, &self.tr, &self.bl, &self.br)
-    }
-}
-
-struct NotificaitonList {
-    next_id: usize,
-    items: Vec<(usize, Option<String>, NotificationProps)>,
-}
-
-impl NotificaitonList {
-    fn new() -> Self {
-        Self {
-            next_id: 0,
-            items: Vec::new(),
-        }
-    }
-
-    fn next_id(&mut self) -> usize {
-        let id = self.next_id;
-        self.next_id += 1;
-        id
-    }
-
-    fn insert(&mut self, id: usize, tag: Option<String>, props: NotificationProps) {
-        // TODO: ensure tag uniqueness?
-        self.items.push((id, tag, props));
-    }
-
-    fn remove_id(&mut self, id: usize) {
-        for i in 0..self.items.len() {
-            if self.items[i].0 == id {
-                // self.items.swap_remove(i); // do we need to preserve order?
-                self.items.remove(i);
-                return;
-            }
-        }
-    }
-
-    /// Exhaustive (unlike remove_id, which removes the first match)
-    fn remove_tag(&mut self, tag: &str) {
-        for i in 0..self.items.len() {
-            if let Some(ref t) = self.items[i].1 {
-                if t == tag {
-                    self.items.remove(i);
-                }
-            }
-        }
-    }
-
-    fn view(&self, position: Position) -> Html {
-        if self.items.is_empty() {
-            html! {}
-        } else {
-            html! {
-                <div class={position.style()}>
-                    { self.items.iter().map(|(id, _tag, props)| {
-                        let props = props.clone();
-                        html! { <Notification key={*id} with props /> }
-                    } ).collect::<Html>() }
-                </div>
-            }
-        }
+impl NotificationConsumer {
+    fn register(&mut self) {
+        let input = match self.props.scope {
+            Some(ref scope) => NotificationAgentInput::RegisterScopedConsumer(scope.clone()),
+            None => NotificationAgentInput::RegisterConsumer,
+        };
+        self.bridge.send(input);
     }
 }
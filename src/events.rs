@@ -0,0 +1,30 @@
+/// Something that happened to a notification spawned through the
+/// [`NotificationAgent`](crate::NotificationAgent). Components receive these
+/// after sending [`NotificationAgentInput::RegisterObserver`](crate::NotificationAgentInput::RegisterObserver)
+#[derive(Clone, PartialEq, Debug)]
+pub struct NotificationEvent {
+    /// Id assigned by the agent, shared with the matching
+    /// [`HistoryEntry`](crate::HistoryEntry)
+    pub id: usize,
+    pub tag: Option<String>,
+    pub kind: NotificationEventKind,
+    /// Milliseconds since the unix epoch at which the event occurred
+    pub timestamp: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum NotificationEventKind {
+    /// The notification was sent to the consumers
+    Spawned,
+    /// The notification finished animating onto the screen
+    Shown,
+    /// A tagged notification that was still displayed was replaced with new
+    /// properties, or spawned again with its tag
+    Updated,
+    /// The user closed the notification, or it was closed by its tag
+    Closed,
+    /// The notification's timeout elapsed
+    TimedOut,
+    /// The user clicked the action with the given label
+    ActionClicked(String),
+}
@@ -0,0 +1,23 @@
+use std::fmt::Display;
+
+use crate::NotificationService;
+
+/// Show the error of a [`Result`] as a notification
+///
+/// ```ignore
+/// let saved = save(&document).notify_err(&mut self.notifications, "Saving failed");
+/// ```
+pub trait NotifyResultExt {
+    /// Spawn a [`Color::Danger`](crate::Color::Danger) notification with the
+    /// error's text if this is an `Err`, and pass the result through
+    fn notify_err(self, service: &mut NotificationService, header: impl Into<String>) -> Self;
+}
+
+impl<T, E: Display> NotifyResultExt for Result<T, E> {
+    fn notify_err(self, service: &mut NotificationService, header: impl Into<String>) -> Self {
+        if let Err(ref error) = self {
+            service.error(header, error.to_string());
+        }
+        self
+    }
+}
@@ -0,0 +1,97 @@
+use yew::prelude::*;
+
+use crate::state::NotificationOutcome;
+use crate::{Color, NotificationProps};
+
+/// Maximum number of entries kept by the agent. Older entries are dropped
+/// first once the limit is reached.
+const HISTORY_LIMIT: usize = 100;
+
+/// A record of a notification that passed through the
+/// [`NotificationAgent`](crate::NotificationAgent)
+#[derive(Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub id: usize,
+    pub tag: Option<String>,
+    pub header: Option<String>,
+    pub children: Children,
+    pub color: Color,
+    /// Milliseconds since the unix epoch at which the notification was spawned
+    pub timestamp: f64,
+    /// Whether the entry has been seen in a
+    /// [`NotificationCenter`](crate::NotificationCenter)
+    pub read: bool,
+    /// How the notification left the screen, or `None` if it's still displayed
+    pub outcome: Option<NotificationOutcome>,
+}
+
+impl HistoryEntry {
+    /// Local time at which the notification was spawned, formatted by the browser
+    pub fn time_string(&self) -> String {
+        let date = js_sys::Date::new_0();
+        date.set_time(self.timestamp);
+        date.to_locale_time_string("default").into()
+    }
+}
+
+/// Helper struct for recording notifications in the agent
+pub(crate) struct NotificationHistory {
+    next_id: usize,
+    entries: Vec<HistoryEntry>,
+}
+
+impl NotificationHistory {
+    pub(crate) fn new() -> Self {
+        Self {
+            next_id: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Record a new notification, returning the id assigned to it
+    pub(crate) fn record(&mut self, props: &NotificationProps, tag: Option<String>) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        if self.entries.len() >= HISTORY_LIMIT {
+            self.entries.remove(0);
+        }
+        self.entries.push(HistoryEntry {
+            id,
+            tag,
+            header: props.header.clone(),
+            children: props.children.clone(),
+            color: props.color.clone(),
+            timestamp: js_sys::Date::now(),
+            read: false,
+            outcome: None,
+        });
+        id
+    }
+
+    pub(crate) fn update(&mut self, id: usize, props: &NotificationProps) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.header = props.header.clone();
+            entry.children = props.children.clone();
+            entry.color = props.color.clone();
+        }
+    }
+
+    pub(crate) fn set_outcome(&mut self, id: usize, outcome: NotificationOutcome) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.outcome = Some(outcome);
+        }
+    }
+
+    pub(crate) fn mark_read(&mut self) {
+        self.entries.iter_mut().for_each(|entry| entry.read = true);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub(crate) fn entries(&self) -> Vec<HistoryEntry> {
+        self.entries.clone()
+    }
+}
@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use yew_functional::{use_context, use_ref};
+
+use crate::agent::NotificationAgentInput;
+use crate::notification::NotificationProps;
+use crate::provider::NotificationContext;
+use crate::service::NotificationService;
+
+/// Clonable handle for spawning notifications from function components,
+/// returned by [`use_notifications`]
+#[derive(Clone, PartialEq)]
+pub struct NotificationHandle {
+    backend: Backend,
+}
+
+#[derive(Clone)]
+enum Backend {
+    Agent(Rc<RefCell<NotificationService>>),
+    Provider(NotificationContext),
+}
+
+impl PartialEq for Backend {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Backend::Agent(a), Backend::Agent(b)) => Rc::ptr_eq(a, b),
+            (Backend::Provider(a), Backend::Provider(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl NotificationHandle {
+    pub fn spawn(&self, props: NotificationProps) {
+        self.send(NotificationAgentInput::New(props))
+    }
+
+    /// See [`NotificationService::spawn_with_id`]
+    pub fn spawn_with_id(&self, props: NotificationProps, id: String) {
+        self.send(NotificationAgentInput::NewTagged(props, id))
+    }
+
+    /// See [`NotificationService::spawn_in_scope`]. Scopes are ignored by a
+    /// [`NotificationProvider`](crate::NotificationProvider)
+    pub fn spawn_in_scope(&self, props: NotificationProps, scope: String) {
+        self.send(NotificationAgentInput::NewInScope(props, scope))
+    }
+
+    pub fn update(&self, props: NotificationProps, id: String) {
+        self.send(NotificationAgentInput::UpdateTagged(props, id))
+    }
+
+    pub fn close(&self, id: String) {
+        self.send(NotificationAgentInput::CloseTagged(id))
+    }
+
+    fn send(&self, input: NotificationAgentInput) {
+        match self.backend {
+            Backend::Agent(ref service) => service.borrow_mut().send(input),
+            Backend::Provider(ref context) => context.send(input),
+        }
+    }
+}
+
+/// Hook for spawning notifications from a function component. Inside a
+/// [`NotificationProvider`](crate::NotificationProvider), notifications are
+/// rendered by the nearest provider. Otherwise they are sent through the
+/// [`NotificationAgent`](crate::NotificationAgent) and rendered by any mounted
+/// [`NotificationConsumer`](crate::NotificationConsumer)
+///
+/// ```ignore
+/// #[function_component(SaveButton)]
+/// fn save_button() -> Html {
+///     let notifications = use_notifications();
+///     let onclick = Callback::from(move |_| {
+///         notifications.spawn(
+///             NotificationProps::builder()
+///                 .header(Some(String::from("Saved")))
+///                 .children(Children::new(vec![html! {"Your changes were saved"}]))
+///                 .build(),
+///         )
+///     });
+///     html! { <button onclick={onclick}>{"Save"}</button> }
+/// }
+/// ```
+pub fn use_notifications() -> NotificationHandle {
+    // Both hooks are always called, so the hook order stays stable
+    let context = use_context::<NotificationContext>();
+    let service = use_ref(NotificationService::new);
+    let backend = match context {
+        Some(context) => Backend::Provider(NotificationContext::clone(&context)),
+        None => Backend::Agent(service),
+    };
+    NotificationHandle { backend }
+}
@@ -0,0 +1,222 @@
+//! Translation of the text rendered by this crate.
+//!
+//! Built-in text is identified by a [`Label`] and defaults to English. Install
+//! a [`Translator`] with [`set_translator`] before mounting any components to
+//! replace it, and to translate application messages spawned with
+//! [`message`]. Templates can contain `{name}` placeholders that are filled
+//! in from parameters:
+//!
+//! ```ignore
+//! struct German;
+//!
+//! impl Translator for German {
+//!     fn label(&self, label: Label) -> Option<String> {
+//!         match label {
+//!             Label::Close => Some("Schließen".into()),
+//!             Label::ClearAll => Some("Alle löschen".into()),
+//!             _ => None,
+//!         }
+//!     }
+//!
+//!     fn message(&self, key: &str) -> Option<String> {
+//!         match key {
+//!             "upload.done" => Some("{count} Dateien hochgeladen".into()),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! set_translator(German);
+//! service.success(message("upload.done", "{count} files uploaded", &[("count", &3)]), "");
+//! ```
+
+use std::cell::RefCell;
+use std::fmt::{Display, Write};
+use std::rc::Rc;
+
+thread_local! {
+    static TRANSLATOR: RefCell<Option<Rc<dyn Translator>>> = RefCell::new(None);
+}
+
+/// Text rendered by the components and integrations of this crate
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Label {
+    /// Accessible name of a notification's close button. Defaults to
+    /// "delete", after Bulma's `delete` element
+    Close,
+    ShowDetails,
+    HideDetails,
+    Copy,
+    /// Replaces [`Label::Copy`] once the details have been copied
+    Copied,
+    ShowMore,
+    ShowLess,
+    /// Default label of the [`NotificationCenter`](crate::NotificationCenter)
+    Notifications,
+    NoNotifications,
+    ClearAll,
+    /// Accessible name of the [`NotificationBadge`](crate::NotificationBadge),
+    /// with a `{count}` parameter
+    MissedNotifications,
+    /// Header of the notification shown by the panic hook
+    PanicHeader,
+    PanicBody,
+    Reload,
+    /// Headers of notifications raised by the `log` and `tracing` integrations
+    Error,
+    Warning,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Label {
+    /// The English text of the label
+    pub fn english(self) -> &'static str {
+        match self {
+            Label::Close => "delete",
+            Label::ShowDetails => "Show details",
+            Label::HideDetails => "Hide details",
+            Label::Copy => "Copy",
+            Label::Copied => "Copied",
+            Label::ShowMore => "Show more",
+            Label::ShowLess => "Show less",
+            Label::Notifications => "Notifications",
+            Label::NoNotifications => "No notifications",
+            Label::ClearAll => "Clear all",
+            Label::MissedNotifications => "{count} missed notifications",
+            Label::PanicHeader => "Something went wrong",
+            Label::PanicBody => {
+                "The application ran into an error and may not work until the page is reloaded."
+            }
+            Label::Reload => "Reload",
+            Label::Error => "Error",
+            Label::Warning => "Warning",
+            Label::Info => "Info",
+            Label::Debug => "Debug",
+            Label::Trace => "Trace",
+        }
+    }
+}
+
+/// Provides translated templates. Returning `None` falls back to the default
+pub trait Translator {
+    fn label(&self, _label: Label) -> Option<String> {
+        None
+    }
+
+    /// Template for an application message passed to [`message`]
+    fn message(&self, _key: &str) -> Option<String> {
+        None
+    }
+}
+
+/// Use `translator` for all text rendered from now on, on this thread
+pub fn set_translator(translator: impl Translator + 'static) {
+    TRANSLATOR.with(|current| *current.borrow_mut() = Some(Rc::new(translator)));
+}
+
+/// The translated text of a built-in label
+pub fn label(label: Label) -> String {
+    label_with(label, &[])
+}
+
+/// The translated text of a built-in label with parameters
+pub(crate) fn label_with(label: Label, params: &[(&str, &dyn Display)]) -> String {
+    let template = translator()
+        .and_then(|translator| translator.label(label))
+        .unwrap_or_else(|| label.english().to_owned());
+    format(&template, params)
+}
+
+/// The translated text of an application message, or `default` if the
+/// translator doesn't know the key, with the parameters filled in
+pub fn message(key: &str, default: &str, params: &[(&str, &dyn Display)]) -> String {
+    match translator().and_then(|translator| translator.message(key)) {
+        Some(template) => format(&template, params),
+        None => format(default, params),
+    }
+}
+
+/// Replace the `{name}` placeholders in `template` with the matching
+/// parameters. Placeholders without a parameter are left as they are
+pub fn format(template: &str, params: &[(&str, &dyn Display)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let param = after.find('}').and_then(|end| {
+            params
+                .iter()
+                .find(|(name, _)| *name == &after[..end])
+                .map(|(_, value)| (end, value))
+        });
+        match param {
+            Some((end, value)) => {
+                let _ = write!(output, "{}", value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                output.push('{');
+                rest = after;
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+fn translator() -> Option<Rc<dyn Translator>> {
+    TRANSLATOR.with(|current| current.borrow().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_fills_placeholders() {
+        assert_eq!(format("{count} missed", &[("count", &3)]), "3 missed");
+        assert_eq!(format("{a}{b}{a}", &[("a", &"x"), ("b", &1.5)]), "x1.5x");
+        assert_eq!(format("no placeholders", &[("a", &1)]), "no placeholders");
+    }
+
+    #[test]
+    fn format_keeps_unknown_placeholders() {
+        assert_eq!(
+            format("{name} and {other}", &[("name", &"a")]),
+            "a and {other}"
+        );
+        assert_eq!(format("{}", &[]), "{}");
+    }
+
+    #[test]
+    fn format_keeps_unclosed_braces() {
+        assert_eq!(format("{count", &[("count", &1)]), "{count");
+        assert_eq!(format("a { {count}", &[("count", &1)]), "a { 1");
+        assert_eq!(format("{{count}}", &[("count", &1)]), "{1}");
+    }
+
+    #[test]
+    fn format_handles_multibyte_text() {
+        assert_eq!(
+            format(
+                "{anzahl} Dateien hochgeladen – schön",
+                &[("anzahl", &"drei")]
+            ),
+            "drei Dateien hochgeladen – schön"
+        );
+        assert_eq!(format("日本{語}", &[("語", &"ご")]), "日本ご");
+        assert_eq!(format("ü{", &[]), "ü{");
+    }
+
+    #[test]
+    fn label_defaults_to_english() {
+        assert_eq!(label(Label::ClearAll), "Clear all");
+        assert_eq!(
+            label_with(Label::MissedNotifications, &[("count", &2)]),
+            "2 missed notifications"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use yew::prelude::*;
+
+use crate::theme::{self, Part};
+use crate::Color;
+
+/// An icon displayed in a notification's header, inside a Bulma
+/// [icon](https://bulma.io/documentation/elements/icon/) element. The icon
+/// font or stylesheet has to be included by the application
+#[derive(Clone, PartialEq)]
+pub enum Icon {
+    /// CSS classes of an icon font, e.g. `"fas fa-check"` for Font Awesome
+    Class(String),
+    /// Name of a [Material icon](https://fonts.google.com/icons), e.g. `"check_circle"`
+    Material(String),
+    Html(Html),
+}
+
+impl Icon {
+    pub(crate) fn view(&self) -> Html {
+        let icon = match self {
+            Icon::Class(class) => html! { <i class={class.clone()}></i> },
+            Icon::Material(name) => html! { <span class="material-icons">{name}</span> },
+            Icon::Html(html) => html.clone(),
+        };
+        let mut cls = theme::theme().part(Part::Icon);
+        cls.push("ybn-icon");
+        html! {
+            <span class={cls} aria-hidden="true">{icon}</span>
+        }
+    }
+}
+
+/// Icon font used for the default icon of each [`Color`], see
+/// [`NotificationConfig::with_icons`](crate::NotificationConfig::with_icons)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IconSet {
+    FontAwesome,
+    Material,
+}
+
+impl IconSet {
+    /// Default icon for notifications of the given color
+    pub fn icon(self, color: &Color) -> Icon {
+        match self {
+            IconSet::FontAwesome => Icon::Class(
+                match color {
+                    Color::Success => "fas fa-check-circle",
+                    Color::Info => "fas fa-info-circle",
+                    Color::Warning => "fas fa-exclamation-triangle",
+                    Color::Danger => "fas fa-times-circle",
+                    _ => "fas fa-bell",
+                }
+                .to_owned(),
+            ),
+            IconSet::Material => Icon::Material(
+                match color {
+                    Color::Success => "check_circle",
+                    Color::Info => "info",
+                    Color::Warning => "warning",
+                    Color::Danger => "error",
+                    _ => "notifications",
+                }
+                .to_owned(),
+            ),
+        }
+    }
+}
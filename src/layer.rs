@@ -0,0 +1,166 @@
+use std::fmt;
+
+use tracing_core::field::{Field, Visit};
+use tracing_core::span::{Attributes, Id, Record};
+use tracing_core::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+use yew::prelude::*;
+
+use crate::i18n::{self, Label};
+use crate::{Color, NotificationBuilder, NotificationService};
+
+/// A tracing [`Layer`] that shows events as notifications. An event is shown
+/// if it has a `notify = true` field, or if it is at or above the layer's
+/// level and has no `notify = false` field. The innermost span's name is used
+/// as the header, and the fields of the spans and the event are listed below
+/// the message
+///
+/// ```ignore
+/// tracing_subscriber::registry()
+///     .with(NotificationLayer::new().level(Level::WARN))
+///     .init();
+///
+/// let _span = tracing::info_span!("Saving", document = %name).entered();
+/// tracing::info!(notify = true, "Saved");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NotificationLayer {
+    level: Option<Level>,
+}
+
+impl NotificationLayer {
+    /// A layer that only shows events with a `notify = true` field
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also show events at or above this level
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    fn color(level: Level) -> Color {
+        match level {
+            Level::ERROR => Color::Danger,
+            Level::WARN => Color::Warning,
+            Level::INFO => Color::Info,
+            _ => Color::Default,
+        }
+    }
+
+    fn header(level: Level) -> Label {
+        match level {
+            Level::ERROR => Label::Error,
+            Level::WARN => Label::Warning,
+            Level::INFO => Label::Info,
+            Level::DEBUG => Label::Debug,
+            _ => Label::Trace,
+        }
+    }
+}
+
+/// Fields recorded on a span, stored in its extensions
+struct SpanFields(Vec<(&'static str, String)>);
+
+/// Collects the fields of a span or event
+#[derive(Default)]
+struct FieldVisitor {
+    message: Option<String>,
+    notify: Option<bool>,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if field.name() == "notify" {
+            self.notify = Some(value);
+        } else {
+            self.fields.push((field.name(), value.to_string()));
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_owned());
+        } else {
+            self.fields.push((field.name(), value.to_owned()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{:?}", value));
+        } else {
+            self.fields.push((field.name(), format!("{:?}", value)));
+        }
+    }
+}
+
+impl<S> Layer<S> for NotificationLayer
+where
+    S: Subscriber + for<'lookup> LookupSpan<'lookup>,
+{
+    fn new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let mut visitor = FieldVisitor::default();
+            attrs.record(&mut visitor);
+            span.extensions_mut().insert(SpanFields(visitor.fields));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let mut visitor = FieldVisitor::default();
+            values.record(&mut visitor);
+            if let Some(fields) = span.extensions_mut().get_mut::<SpanFields>() {
+                fields.0.extend(visitor.fields);
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let level = *event.metadata().level();
+        let notify = visitor
+            .notify
+            .unwrap_or_else(|| matches!(self.level, Some(threshold) if level <= threshold));
+        if !notify {
+            return;
+        }
+
+        // Span fields from the root down, then the event's own fields
+        let mut header = None;
+        let mut context = Vec::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                header = Some(span.name());
+                if let Some(fields) = span.extensions().get::<SpanFields>() {
+                    context.extend(fields.0.iter().cloned());
+                }
+            }
+        }
+        context.extend(visitor.fields);
+
+        let mut builder = NotificationBuilder::new()
+            .header(header.map_or_else(|| i18n::label(Self::header(level)), String::from))
+            .color(Self::color(level));
+        if let Some(message) = visitor.message {
+            builder = builder.body(html! { <p>{message}</p> });
+        }
+        if !context.is_empty() {
+            builder = builder.body(html! {
+                <ul class="ybn-context">
+                    { context.into_iter().map(|(name, value)| html! {
+                        <li><strong>{name}</strong>{": "}{value}</li>
+                    }).collect::<Html>() }
+                </ul>
+            });
+        }
+        let props = builder.build();
+        NotificationService::with_shared(|service| service.spawn(props));
+    }
+}
@@ -1,11 +1,61 @@
+// The `log` crate is renamed in the manifest, so that the feature can be called `log`
+#[cfg(feature = "log")]
+extern crate dep_log as log;
+
 mod agent;
+mod badge;
+mod builder;
+mod center;
+pub mod clock;
+mod collection;
+mod config;
 mod consumer;
+mod events;
+mod ext;
+mod history;
+mod hooks;
+pub mod i18n;
+mod icon;
+#[cfg(feature = "tracing")]
+mod layer;
+#[cfg(feature = "log")]
+mod logger;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod notification;
+mod panic;
 mod properties;
+mod provider;
 mod service;
+pub mod state;
+#[cfg(feature = "inject-styles")]
+mod styles;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod theme;
 
 pub use agent::{NotificationAgent, NotificationAgentInput, NotificationAgentOutput};
-pub use consumer::NotificationConsumer;
-pub use notification::{Notification, NotificationProps};
-pub use properties::{Color, Position, Size};
+pub use badge::{NotificationBadge, NotificationBadgeProps};
+pub use builder::NotificationBuilder;
+pub use center::{NotificationCenter, NotificationCenterProps};
+pub use config::{NotificationConfig, NotificationDefaults};
+pub use consumer::{NotificationConsumer, NotificationConsumerProps};
+pub use events::{NotificationEvent, NotificationEventKind};
+pub use ext::NotifyResultExt;
+pub use history::HistoryEntry;
+pub use hooks::{use_notifications, NotificationHandle};
+pub use icon::{Icon, IconSet};
+#[cfg(feature = "tracing")]
+pub use layer::NotificationLayer;
+#[cfg(feature = "log")]
+pub use logger::NotificationLogger;
+#[cfg(feature = "markdown")]
+pub use markdown::render_markdown;
+pub use notification::{Notification, NotificationAction, NotificationProps};
+pub use panic::set_panic_hook;
+pub use properties::{Color, CustomColor, Direction, Position, Size};
+pub use provider::{NotificationContext, NotificationProvider, NotificationProviderProps};
 pub use service::NotificationService;
+pub use state::NotificationOutcome;
+#[cfg(feature = "inject-styles")]
+pub use styles::StyleConfig;
@@ -0,0 +1,132 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::i18n::{self, Label};
+use crate::{Color, NotificationBuilder, NotificationService};
+
+/// A [`Log`] implementation that shows records at or above a level as
+/// notifications, so errors logged deep inside shared crates reach the user
+/// without passing a [`NotificationService`] around. Identical messages are
+/// only shown once per throttle interval (5 seconds by default)
+///
+/// ```ignore
+/// NotificationLogger::new(LevelFilter::Warn)
+///     .inner(Box::new(wasm_logger::WasmLogger::default()))
+///     .init()
+///     .unwrap();
+/// ```
+pub struct NotificationLogger {
+    level: LevelFilter,
+    throttle: Duration,
+    inner: Option<Box<dyn Log>>,
+    /// When each recent message was last shown, in milliseconds since the epoch
+    shown: Mutex<HashMap<(Level, String), f64>>,
+}
+
+impl NotificationLogger {
+    pub fn new(level: LevelFilter) -> Self {
+        Self {
+            level,
+            throttle: Duration::from_secs(5),
+            inner: None,
+            shown: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Minimum time between notifications for the same message
+    pub fn throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Also pass every record on to another logger, e.g. one that writes to
+    /// the console. It receives records of all levels
+    pub fn inner(mut self, inner: Box<dyn Log>) -> Self {
+        self.inner = Some(inner);
+        self
+    }
+
+    /// Install as the global logger
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        let max_level = if self.inner.is_some() {
+            LevelFilter::Trace
+        } else {
+            self.level
+        };
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+
+    fn color(level: Level) -> Color {
+        match level {
+            Level::Error => Color::Danger,
+            Level::Warn => Color::Warning,
+            Level::Info => Color::Info,
+            Level::Debug | Level::Trace => Color::Default,
+        }
+    }
+
+    fn header(level: Level) -> Label {
+        match level {
+            Level::Error => Label::Error,
+            Level::Warn => Label::Warning,
+            Level::Info => Label::Info,
+            Level::Debug => Label::Debug,
+            Level::Trace => Label::Trace,
+        }
+    }
+
+    /// Whether the message should be shown now, recording it if so
+    fn unthrottled(&self, level: Level, message: &str) -> bool {
+        let now = js_sys::Date::now();
+        let window = self.throttle.as_millis() as f64;
+        let mut shown = match self.shown.lock() {
+            Ok(shown) => shown,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        shown.retain(|_, &mut at| now - at < window);
+        match shown.entry((level, message.to_owned())) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(now);
+                true
+            }
+        }
+    }
+}
+
+impl Log for NotificationLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+            || matches!(self.inner, Some(ref inner) if inner.enabled(metadata))
+    }
+
+    fn log(&self, record: &Record) {
+        if let Some(ref inner) = self.inner {
+            inner.log(record);
+        }
+        if record.level() > self.level {
+            return;
+        }
+        let message = record.args().to_string();
+        if !self.unthrottled(record.level(), &message) {
+            return;
+        }
+        let props = NotificationBuilder::new()
+            .header(i18n::label(Self::header(record.level())))
+            .body(message)
+            .color(Self::color(record.level()))
+            .build();
+        NotificationService::with_shared(|service| service.spawn(props));
+    }
+
+    fn flush(&self) {
+        if let Some(ref inner) = self.inner {
+            inner.flush();
+        }
+    }
+}
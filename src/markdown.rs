@@ -0,0 +1,221 @@
+//! Rendering of markdown text into notification bodies.
+//!
+//! The markdown is turned into [`Html`] element by element, so nothing in the
+//! text is ever parsed as HTML by the browser: inline HTML is shown as text,
+//! images are replaced by their alt text, and links are only kept for `http`,
+//! `https`, `mailto` and relative URLs.
+
+use pulldown_cmark::{Event, Options, Parser, Tag};
+use yew::prelude::*;
+
+use crate::theme::{self, Part};
+
+/// Render markdown text for use as the body of a notification
+///
+/// ```ignore
+/// html! {
+///     <Notification header="Deployed">
+///         { render_markdown("Version **1.2** is live, see the [changelog](/changes)") }
+///     </Notification>
+/// }
+/// ```
+pub fn render_markdown(text: &str) -> Html {
+    let mut stack = vec![Element::new("div", None)];
+    for event in Parser::new_ext(text, Options::ENABLE_STRIKETHROUGH) {
+        match event {
+            Event::Start(tag) => stack.push(Element::from_tag(tag)),
+            Event::End(_) => {
+                // The root is never popped, as every end has a matching start
+                if stack.len() > 1 {
+                    let element = stack.pop().expect("stack has a parent");
+                    let html = element.view();
+                    stack.last_mut().expect("stack has a root").push(html);
+                }
+            }
+            Event::Text(text) | Event::Html(text) | Event::FootnoteReference(text) => {
+                push(&mut stack, Html::from(text.into_string()))
+            }
+            Event::Code(code) => {
+                let code = code.into_string();
+                push(&mut stack, html! { <code>{code}</code> })
+            }
+            Event::SoftBreak => push(&mut stack, Html::from(" ")),
+            Event::HardBreak => push(&mut stack, html! { <br /> }),
+            Event::Rule => push(&mut stack, html! { <hr /> }),
+            Event::TaskListMarker(checked) => push(
+                &mut stack,
+                Html::from(if checked { "\u{2611} " } else { "\u{2610} " }),
+            ),
+        }
+    }
+    let root = stack.swap_remove(0);
+    let mut cls = theme::theme().part(Part::Content);
+    cls.push("ybn-markdown");
+    html! {
+        <div class={cls}>{ for root.children }</div>
+    }
+}
+
+fn push(stack: &mut [Element], html: Html) {
+    if let Some(element) = stack.last_mut() {
+        element.push(html);
+    }
+}
+
+/// An element being built from the markdown events
+struct Element {
+    tag: &'static str,
+    /// Target of a link with a safe URL
+    href: Option<String>,
+    children: Vec<Html>,
+}
+
+impl Element {
+    fn new(tag: &'static str, href: Option<String>) -> Self {
+        Self {
+            tag,
+            href,
+            children: Vec::new(),
+        }
+    }
+
+    fn from_tag(tag: Tag) -> Self {
+        let name = match tag {
+            Tag::Paragraph => "p",
+            Tag::Heading(1) => "h1",
+            Tag::Heading(2) => "h2",
+            Tag::Heading(3) => "h3",
+            Tag::Heading(4) => "h4",
+            Tag::Heading(5) => "h5",
+            Tag::Heading(_) => "h6",
+            Tag::BlockQuote => "blockquote",
+            Tag::CodeBlock(_) => "pre",
+            Tag::List(Some(_)) => "ol",
+            Tag::List(None) => "ul",
+            Tag::Item => "li",
+            Tag::Emphasis => "em",
+            Tag::Strong => "strong",
+            Tag::Strikethrough => "del",
+            Tag::Link(_, url, _) => {
+                return Self::new("a", Some(url.into_string()).filter(|url| is_safe_url(url)))
+            }
+            // Images aren't loaded, their alt text is rendered instead
+            Tag::Image(..) => "span",
+            Tag::Table(_)
+            | Tag::TableHead
+            | Tag::TableRow
+            | Tag::TableCell
+            | Tag::FootnoteDefinition(_) => "div",
+        };
+        Self::new(name, None)
+    }
+
+    fn push(&mut self, html: Html) {
+        self.children.push(html);
+    }
+
+    fn view(self) -> Html {
+        let children = self.children;
+        match (self.tag, self.href) {
+            ("a", Some(href)) => html! {
+                <a href={href} target="_blank" rel="noopener noreferrer">{ for children }</a>
+            },
+            // A link with an unsafe URL keeps its text
+            ("a", None) => html! { <span>{ for children }</span> },
+            ("pre", _) => html! { <pre><code>{ for children }</code></pre> },
+            (tag, _) => html! { <@{tag}>{ for children }</@> },
+        }
+    }
+}
+
+/// Whether a link URL is relative or uses a scheme that can't run script.
+/// Whitespace and control characters are ignored, like browsers do
+fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect();
+    match url.find([':', '/', '?', '#']) {
+        Some(index) if url[index..].starts_with(':') => matches!(
+            url[..index].to_ascii_lowercase().as_str(),
+            "http" | "https" | "mailto"
+        ),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yew::virtual_dom::VNode;
+
+    /// Tag names and text of the rendered elements, depth first
+    fn walk(node: &VNode, tags: &mut Vec<String>, text: &mut String) {
+        match node {
+            VNode::VTag(tag) => {
+                tags.push(tag.tag().to_owned());
+                for child in tag.children.iter() {
+                    walk(child, tags, text);
+                }
+            }
+            VNode::VText(vtext) => text.push_str(&vtext.text),
+            VNode::VList(list) => {
+                for child in list.iter() {
+                    walk(child, tags, text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn render(markdown: &str) -> (Vec<String>, String) {
+        let (mut tags, mut text) = (Vec::new(), String::new());
+        walk(&render_markdown(markdown), &mut tags, &mut text);
+        (tags, text)
+    }
+
+    #[test]
+    fn rejects_script_urls() {
+        assert!(!is_safe_url("javascript:alert(1)"));
+        assert!(!is_safe_url("JaVaScRiPt:alert(1)"));
+        assert!(!is_safe_url("java\tscript:alert(1)"));
+        assert!(!is_safe_url(" javascript:alert(1)"));
+        assert!(!is_safe_url("java\u{0}script:alert(1)"));
+        assert!(!is_safe_url("jav\nascript:alert(1)"));
+        assert!(!is_safe_url("data:text/html,<script>alert(1)</script>"));
+        assert!(!is_safe_url("vbscript:msgbox(1)"));
+    }
+
+    #[test]
+    fn accepts_safe_urls() {
+        assert!(is_safe_url("https://example.com/a:b"));
+        assert!(is_safe_url("HTTP://example.com"));
+        assert!(is_safe_url("mailto:support@example.com"));
+        assert!(is_safe_url("/changes"));
+        assert!(is_safe_url("changes/1.2"));
+        assert!(is_safe_url("./a:b"));
+        assert!(is_safe_url("#frag"));
+        assert!(is_safe_url("?q=a:b"));
+    }
+
+    #[test]
+    fn unsafe_links_keep_their_text() {
+        let (tags, text) = render("[click](javascript:alert(1))");
+        assert!(!tags.iter().any(|tag| tag == "a"));
+        assert_eq!(text, "click");
+
+        let (tags, _) = render("[changelog](/changes)");
+        assert!(tags.iter().any(|tag| tag == "a"));
+    }
+
+    #[test]
+    fn inline_html_is_text() {
+        let (tags, text) = render("Hi <b>there</b> <img src=x onerror=alert(1)>");
+        assert_eq!(tags, vec!["div", "p"]);
+        assert_eq!(text, "Hi <b>there</b> <img src=x onerror=alert(1)>");
+
+        let (tags, text) = render("<script>alert(1)</script>");
+        assert!(!tags.iter().any(|tag| tag == "script"));
+        assert!(text.contains("<script>alert(1)</script>"));
+    }
+}
@@ -1,31 +1,79 @@
+use js_sys::{Function, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 use yew::prelude::*;
-use yew_services::timeout::{TimeoutService, TimeoutTask};
 
-use super::properties::{Color, Position, Size};
+use super::clock::{ClockHandle, TimerTask};
+use super::i18n::{self, Label};
+use super::icon::Icon;
+use super::properties::{Color, Direction, Position, Size};
+use super::state::{NotificationOutcome, NotificationState};
+use super::theme::{self, Part};
+
+/// How long the copy button confirms that the details were copied
+const COPIED_DURATION: std::time::Duration = std::time::Duration::from_secs(2);
 
 #[derive(Properties, Clone, PartialEq)]
 pub struct NotificationProps {
     #[prop_or_default]
     pub header: Option<String>,
+    /// Shown before the header. Defaults to the icon for the color if the
+    /// consumer's config has an icon set
+    #[prop_or_default]
+    pub icon: Option<Icon>,
 
     pub children: Children,
 
+    /// Longer text, such as an error chain or a request id, shown in a
+    /// collapsed section with a button to copy it
+    #[prop_or_default]
+    pub details: Option<String>,
+
+    /// Maximum height of the body, as a CSS length (e.g. `"8em"`). Longer
+    /// content fades out, with a toggle to show all of it. The timeout is
+    /// paused while the body is expanded
+    #[prop_or_default]
+    pub max_body_height: Option<String>,
+
     #[prop_or(true)]
     pub can_close: bool,
 
+    /// Time after which the notification closes itself. If not set, the
+    /// consumer's [`NotificationConfig`](crate::NotificationConfig) applies
     #[prop_or_default]
     pub timeout: Option<std::time::Duration>,
+    /// Never time out, even if the consumer's config has a default timeout
+    #[prop_or_default]
+    pub sticky: bool,
     #[prop_or_default]
     pub on_timeout: Option<Callback<()>>,
     #[prop_or_default]
     pub on_closed: Option<Callback<()>>,
+    /// Emitted once the notification has finished animating in
+    #[prop_or_default]
+    pub on_shown: Option<Callback<()>>,
+
+    /// Buttons rendered below the body of the notification
+    #[prop_or_default]
+    pub actions: Vec<NotificationAction>,
 
     #[prop_or(Color::Default)]
     pub color: Color,
-    #[prop_or(Size::Normal)]
-    pub size: Size,
-    #[prop_or(Position::BottomRight)]
-    pub position: Position,
+    /// Defaults to the consumer's config, or [`Size::Normal`]
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Defaults to the consumer's config, or [`Position::BottomRight`]
+    #[prop_or_default]
+    pub position: Option<Position>,
+
+    /// Layout direction, which mirrors logical positions and the header.
+    /// Set by the consumer to its own direction
+    #[prop_or_default]
+    pub direction: Direction,
+
+    /// Clock used for the timeout. Set by the consumer to its own clock
+    #[prop_or_default]
+    pub clock: ClockHandle,
 
     // pub margin: Option<
     /// This should be set (false) by the notification service to handle
@@ -34,24 +82,77 @@ pub struct NotificationProps {
     pub(crate) standalone: bool,
 }
 
+/// A button displayed in a notification
+#[derive(Clone, PartialEq)]
+pub struct NotificationAction {
+    pub label: String,
+    pub callback: Callback<()>,
+}
+
+impl NotificationProps {
+    pub(crate) fn size(&self) -> Size {
+        self.size.unwrap_or(Size::Normal)
+    }
+
+    /// The physical position, with logical positions resolved for the direction
+    pub(crate) fn position(&self) -> Position {
+        self.position
+            .unwrap_or(Position::BottomRight)
+            .resolve(self.direction)
+    }
+
+    pub(crate) fn timeout(&self) -> Option<std::time::Duration> {
+        if self.sticky {
+            None
+        } else {
+            self.timeout
+        }
+    }
+}
+
+impl NotificationAction {
+    pub fn new(label: impl Into<String>, callback: Callback<()>) -> Self {
+        Self {
+            label: label.into(),
+            callback,
+        }
+    }
+}
+
 /// A bulma [message](https://bulma.io/documentation/components/message/)
 /// that will be displayed as a notification
 pub struct Notification {
-    closed: bool,
-    timed_out: bool,
+    state: NotificationState,
 
     props: NotificationProps,
     link: ComponentLink<Self>,
-    _timeout: Option<TimeoutTask>,
+    timer: Option<TimerTask>,
+
+    details_open: bool,
+    details_copied: bool,
+    /// Puts the copy button's label back
+    copied_timer: Option<TimerTask>,
+
+    body_ref: NodeRef,
+    /// Whether the body is taller than `max_body_height`
+    overflowing: bool,
+    expanded: bool,
 }
 
 pub enum Msg {
     TimedOut,
     Closed,
     Rendered,
-    TimeoutAnimated,
-    CloseAnimated,
+    ExitAnimated,
     DisplayAnimated,
+    Action(usize),
+    ToggleDetails,
+    CopyDetails,
+    /// The clipboard accepted the details
+    Copied,
+    CopiedExpired,
+    Overflowing(bool),
+    ToggleExpanded,
 }
 
 impl Component for Notification {
@@ -59,46 +160,124 @@ impl Component for Notification {
     type Properties = NotificationProps;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        let _timeout = props
-            .timeout
-            .map(|d| TimeoutService::spawn(d, link.callback(|_| Msg::TimedOut)));
+        let timer = props
+            .timeout()
+            .map(|d| Self::schedule_timeout(&link, &props.clock, d));
         Self {
-            closed: false,
-            timed_out: false,
+            state: NotificationState::new(props.clock.now(), props.timeout()),
             props,
-            _timeout,
+            timer,
             link,
+            details_open: false,
+            details_copied: false,
+            copied_timer: None,
+            body_ref: NodeRef::default(),
+            overflowing: false,
+            expanded: false,
         }
     }
 
-    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
-        false
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+        // A replaced notification times out according to its new props
+        let retime = self.props.timeout() != props.timeout()
+            || self.props.clock != props.clock
+            || self.state.is_leaving();
+        if self.props.details != props.details {
+            self.details_copied = false;
+            self.copied_timer = None;
+        }
+        self.props = props;
+        if retime {
+            self.restart_timeout();
+        }
+        true
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::Rendered => false,
-            Msg::TimedOut => {
-                self.timed_out = true;
-                true
+            Msg::TimedOut => self.state.expire(),
+            Msg::Closed => self.state.close(),
+            // Each animation fires its own event, the state only reports the first
+            Msg::ExitAnimated => {
+                let callback = match self.state.finish() {
+                    Some(NotificationOutcome::Closed) => self.props.on_closed.as_ref(),
+                    Some(NotificationOutcome::TimedOut) => self.props.on_timeout.as_ref(),
+                    None => None,
+                };
+                if let Some(callback) = callback {
+                    callback.emit(())
+                }
+                false
+            }
+            Msg::DisplayAnimated => {
+                if self.state.shown() {
+                    if let Some(ref on_shown) = self.props.on_shown {
+                        on_shown.emit(())
+                    }
+                }
+                false
             }
-            Msg::TimeoutAnimated => {
-                if let Some(ref on_timeout) = self.props.on_timeout {
-                    on_timeout.emit(())
+            Msg::Action(index) => {
+                if let Some(action) = self.props.actions.get(index) {
+                    action.callback.emit(())
                 }
                 false
             }
-            Msg::Closed => {
-                self.closed = true;
+            Msg::ToggleDetails => {
+                self.details_open = !self.details_open;
                 true
             }
-            Msg::CloseAnimated => {
-                if let Some(ref on_closed) = self.props.on_closed {
-                    on_closed.emit(())
+            Msg::CopyDetails => {
+                // Only confirm once the write succeeded, it fails in insecure
+                // contexts or without permission
+                if let Some(promise) = self.props.details.as_deref().and_then(copy_to_clipboard) {
+                    let copied = self.link.callback(|_| Msg::Copied);
+                    spawn_local(async move {
+                        if JsFuture::from(promise).await.is_ok() {
+                            copied.emit(());
+                        }
+                    });
                 }
                 false
             }
-            Msg::DisplayAnimated => false,
+            Msg::Copied => {
+                self.details_copied = true;
+                let link = self.link.clone();
+                self.copied_timer = Some(self.props.clock.schedule(
+                    COPIED_DURATION,
+                    Box::new(move || link.send_message(Msg::CopiedExpired)),
+                ));
+                true
+            }
+            Msg::CopiedExpired => {
+                self.details_copied = false;
+                self.copied_timer = None;
+                true
+            }
+            Msg::Overflowing(overflowing) => {
+                self.overflowing = overflowing;
+                true
+            }
+            Msg::ToggleExpanded => {
+                self.expanded = !self.expanded;
+                let now = self.props.clock.now();
+                if self.expanded {
+                    if self.state.pause(now) {
+                        self.timer = None;
+                    }
+                } else if let Some(remaining) = self.state.resume(now) {
+                    self.timer = Some(Self::schedule_timeout(
+                        &self.link,
+                        &self.props.clock,
+                        remaining,
+                    ));
+                }
+                true
+            }
         }
     }
 
@@ -112,33 +291,43 @@ impl Component for Notification {
         // };
 
         // Figure out which classes to render
-        let mut msg_cls = Classes::from("message ybn-body");
-        let mut del_cls = Classes::from("delete");
-        if let Some(cls) = self.props.color.class() {
-            msg_cls.push(cls);
-        }
-        if let Some(cls) = self.props.size.class() {
-            msg_cls.push(cls);
-            del_cls.push(cls);
+        let theme = theme::theme();
+        let mut msg_cls = theme.part(Part::Message);
+        msg_cls.push("ybn-body");
+        msg_cls.push(theme.color(&self.props.color));
+        if let Some(class) = self.props.color.custom_class() {
+            msg_cls.push(class.to_owned());
         }
+        msg_cls.push(theme.size(self.props.size()));
+        let mut del_cls = theme.part(Part::Close);
+        del_cls.push(theme.size(self.props.size()));
         if self.props.standalone {
-            msg_cls.push(self.props.position.style());
+            msg_cls.push(self.props.position().style());
         }
 
+        // Only set the direction if it differs from the usual, otherwise inherit it
+        let dir = (self.props.direction == Direction::Rtl).then_some("rtl");
+
         // Animation class
         let animation_callback = self.add_animation_classes(&mut msg_cls);
 
-        // Show the header if there is a header string, or if "can-close" is true
-        let header = if self.props.header.is_some() || self.props.can_close {
+        // Show the header if there is a header string or icon, or if "can-close" is true
+        let header = if self.props.header.is_some()
+            || self.props.icon.is_some()
+            || self.props.can_close
+        {
             let header = self.props.header.clone().unwrap_or_default();
             let button = if self.props.can_close {
-                html! {<button class={del_cls} aria-label="delete" onclick={self.link.callback(|_| Msg::Closed)}></button>}
+                html! {<button class={del_cls} aria-label={i18n::label(Label::Close)} onclick={self.link.callback(|_| Msg::Closed)}></button>}
             } else {
                 html! {}
             };
             html! {
-                <div class="message-header">
-                    <p>{header}</p>
+                <div class={theme.part(Part::MessageHeader)} style={self.props.color.header_style()}>
+                    <p>
+                        { self.props.icon.as_ref().map_or_else(|| html! {}, Icon::view) }
+                        {header}
+                    </p>
                     {button}
                 </div>
             }
@@ -146,31 +335,176 @@ impl Component for Notification {
             html! {}
         };
 
+        let actions = if self.props.actions.is_empty() {
+            html! {}
+        } else {
+            let mut cls = theme.part(Part::Buttons);
+            cls.push("ybn-actions");
+            html! {
+                <div class={cls}>
+                    { self.props.actions.iter().enumerate().map(|(i, action)| html! {
+                        <button class={theme.part(Part::Button)} onclick={self.link.callback(move |_| Msg::Action(i))}>
+                            {action.label.clone()}
+                        </button>
+                    }).collect::<Html>() }
+                </div>
+            }
+        };
+
         html! {
-            <article class={msg_cls} onanimationend={animation_callback}>
+            <article class={msg_cls} dir={dir} onanimationend={animation_callback}>
                 {header}
-                <div class="message-body">
-                    {self.props.children.clone()}
+                <div class={theme.part(Part::MessageBody)} style={self.props.color.body_style()}>
+                    {self.view_content()}
+                    {self.view_details()}
+                    {actions}
                 </div>
             </article>
         }
     }
+
+    fn rendered(&mut self, _first_render: bool) {
+        // Measure with the maximum height applied, expanding removes it
+        if self.props.max_body_height.is_none() || self.expanded {
+            return;
+        }
+        if let Some(content) = self.body_ref.cast::<yew::web_sys::Element>() {
+            let overflowing = content.scroll_height() > content.client_height();
+            if overflowing != self.overflowing {
+                self.link.send_message(Msg::Overflowing(overflowing));
+            }
+        }
+    }
 }
 
 impl Notification {
+    fn schedule_timeout(
+        link: &ComponentLink<Self>,
+        clock: &ClockHandle,
+        timeout: std::time::Duration,
+    ) -> TimerTask {
+        let link = link.clone();
+        clock.schedule(timeout, Box::new(move || link.send_message(Msg::TimedOut)))
+    }
+
+    /// The children, cut off at `max_body_height` unless expanded
+    fn view_content(&self) -> Html {
+        let max_height = match self.props.max_body_height {
+            Some(ref max_height) => max_height,
+            None => return html! { <>{self.props.children.clone()}</> },
+        };
+        let (class, style) = if self.expanded {
+            ("ybn-content", None)
+        } else if self.overflowing {
+            (
+                "ybn-content ybn-truncated",
+                Some(format!("max-height: {}", max_height)),
+            )
+        } else {
+            ("ybn-content", Some(format!("max-height: {}", max_height)))
+        };
+        let toggle = if self.expanded || self.overflowing {
+            let mut cls = theme::theme().part(Part::TextButton);
+            cls.push("ybn-toggle");
+            let label = if self.expanded {
+                Label::ShowLess
+            } else {
+                Label::ShowMore
+            };
+            html! {
+                <button class={cls} aria-expanded={self.expanded.to_string()} onclick={self.link.callback(|_| Msg::ToggleExpanded)}>
+                    {i18n::label(label)}
+                </button>
+            }
+        } else {
+            html! {}
+        };
+        html! {
+            <>
+                <div class={class} style={style} ref={self.body_ref.clone()}>
+                    {self.props.children.clone()}
+                </div>
+                {toggle}
+            </>
+        }
+    }
+
+    fn view_details(&self) -> Html {
+        let details = match self.props.details {
+            Some(ref details) => details,
+            None => return html! {},
+        };
+        let theme = theme::theme();
+        let toggle = if self.details_open {
+            Label::HideDetails
+        } else {
+            Label::ShowDetails
+        };
+        let copy = if self.details_copied {
+            Label::Copied
+        } else {
+            Label::Copy
+        };
+        html! {
+            <div class="ybn-details">
+                <div class={theme.part(Part::Buttons)}>
+                    <button class={theme.part(Part::TextButton)} aria-expanded={self.details_open.to_string()} onclick={self.link.callback(|_| Msg::ToggleDetails)}>
+                        {i18n::label(toggle)}
+                    </button>
+                    <button class={theme.part(Part::Button)} onclick={self.link.callback(|_| Msg::CopyDetails)}>
+                        {i18n::label(copy)}
+                    </button>
+                </div>
+                { if self.details_open {
+                    html! { <pre>{details}</pre> }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+
+    /// Restart the timeout from now, keeping it paused while expanded
+    fn restart_timeout(&mut self) {
+        let now = self.props.clock.now();
+        self.state.restart(now, self.props.timeout());
+        self.timer = if self.expanded && self.state.pause(now) {
+            None
+        } else {
+            self.props
+                .timeout()
+                .map(|d| Self::schedule_timeout(&self.link, &self.props.clock, d))
+        };
+    }
+
     fn add_animation_classes(
         &self,
         classes: &mut Classes,
     ) -> Callback<yew::web_sys::AnimationEvent> {
-        if self.closed {
-            classes.push(self.props.position.animate_out_style());
-            self.link.callback(|_| Msg::CloseAnimated)
-        } else if self.timed_out {
-            classes.push(self.props.position.animate_out_style());
-            self.link.callback(|_| Msg::TimeoutAnimated)
+        if self.state.is_leaving() {
+            classes.push(self.props.position().animate_out_style());
+            self.link.callback(|_| Msg::ExitAnimated)
         } else {
-            classes.push(self.props.position.animate_in_style());
+            classes.push(self.props.position().animate_in_style());
             self.link.callback(|_| Msg::DisplayAnimated)
         }
     }
 }
+
+/// Copy text with the asynchronous Clipboard API, if the browser supports it.
+/// Returns the promise of the write, or `None` if the API isn't available.
+/// Looked up through reflection, as `web-sys` only exposes the API behind
+/// `--cfg=web_sys_unstable_apis`
+fn copy_to_clipboard(text: &str) -> Option<Promise> {
+    let navigator = Reflect::get(&js_sys::global(), &JsValue::from_str("navigator")).ok()?;
+    let clipboard = Reflect::get(&navigator, &JsValue::from_str("clipboard")).ok()?;
+    let write_text = Reflect::get(&clipboard, &JsValue::from_str("writeText"))
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?;
+    write_text
+        .call1(&clipboard, &JsValue::from_str(text))
+        .ok()?
+        .dyn_into::<Promise>()
+        .ok()
+}
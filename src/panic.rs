@@ -0,0 +1,43 @@
+use std::panic;
+
+use yew::Callback;
+
+use crate::i18n::{self, Label};
+use crate::{Color, NotificationBuilder, NotificationService};
+
+/// Install a panic hook that shows a sticky [`Color::Danger`] notification
+/// with a "Reload" action and the panic message. A previously installed hook
+/// runs first, so its output isn't lost if raising the notification fails.
+/// Logging is left to that hook: install one such as `console_error_panic_hook`
+/// beforehand to see panics in the browser console.
+///
+/// The notification is sent through the
+/// [`NotificationAgent`](crate::NotificationAgent), so it is only rendered if
+/// Yew's scheduler is still running. That is the case for panics in
+/// callbacks from JavaScript, timers and futures, but a panic inside a
+/// component's `update` or `view` leaves the scheduler locked and only the
+/// console message appears.
+pub fn set_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        previous(info);
+        notify(info.to_string());
+    }));
+}
+
+fn notify(message: String) {
+    let reload = Callback::from(|_| {
+        if let Some(window) = yew::web_sys::window() {
+            let _ = window.location().reload();
+        }
+    });
+    let props = NotificationBuilder::new()
+        .header(i18n::label(Label::PanicHeader))
+        .body(i18n::label(Label::PanicBody))
+        .details(message)
+        .color(Color::Danger)
+        .sticky()
+        .action(i18n::label(Label::Reload), reload)
+        .build();
+    NotificationService::with_shared(|service| service.spawn(props));
+}
@@ -1,4 +1,6 @@
-#[derive(Clone, Copy, PartialEq, Eq)]
+use std::rc::Rc;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum Color {
     Default,
     Dark,
@@ -8,6 +10,9 @@ pub enum Color {
     Success,
     Warning,
     Danger,
+    /// A color outside the theme's palette, e.g. to brand notifications for
+    /// a product area
+    Custom(CustomColor),
 }
 
 impl Color {
@@ -21,11 +26,111 @@ impl Color {
             Color::Success => Some("is-success"),
             Color::Warning => Some("is-warning"),
             Color::Danger => Some("is-danger"),
+            Color::Custom(_) => None,
+        }
+    }
+
+    /// Class of a custom color, added whatever the theme
+    pub(crate) fn custom_class(&self) -> Option<&str> {
+        match self {
+            Color::Custom(custom) => custom.class.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Inline style of a notification's header
+    pub(crate) fn header_style(&self) -> Option<String> {
+        match self {
+            Color::Custom(custom) => custom.header_style(),
+            _ => None,
+        }
+    }
+
+    /// Inline style of a notification's body
+    pub(crate) fn body_style(&self) -> Option<String> {
+        match self {
+            Color::Custom(custom) => custom.body_style(),
+            _ => None,
         }
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+/// Colors of a [`Color::Custom`] notification, given as a CSS class and/or
+/// CSS colors. The colors are set as inline styles, overriding the theme, and
+/// can come from configuration loaded at runtime:
+///
+/// ```ignore
+/// let billing = Color::Custom(
+///     CustomColor::new()
+///         .class("is-billing")
+///         .header(area.color.clone())
+///         .header_text("white")
+///         .background("#f4effa"),
+/// );
+/// service.spawn(NotificationBuilder::new().header("Invoice paid").color(billing).build());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CustomColor {
+    class: Option<Rc<str>>,
+    header: Option<Rc<str>>,
+    header_text: Option<Rc<str>>,
+    background: Option<Rc<str>>,
+}
+
+impl CustomColor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Class added to the notification in place of the theme's color class
+    pub fn class(mut self, class: impl Into<Rc<str>>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Background of the header, also used for the border of the body
+    pub fn header(mut self, color: impl Into<Rc<str>>) -> Self {
+        self.header = Some(color.into());
+        self
+    }
+
+    /// Text color of the header
+    pub fn header_text(mut self, color: impl Into<Rc<str>>) -> Self {
+        self.header_text = Some(color.into());
+        self
+    }
+
+    /// Background of the body
+    pub fn background(mut self, color: impl Into<Rc<str>>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    fn header_style(&self) -> Option<String> {
+        style(&[
+            ("background-color", self.header.as_deref()),
+            ("color", self.header_text.as_deref()),
+        ])
+    }
+
+    fn body_style(&self) -> Option<String> {
+        style(&[
+            ("background-color", self.background.as_deref()),
+            ("border-color", self.header.as_deref()),
+        ])
+    }
+}
+
+/// Inline style from the properties that are set, if any
+fn style(properties: &[(&str, Option<&str>)]) -> Option<String> {
+    let style: String = properties
+        .iter()
+        .filter_map(|(name, value)| value.map(|value| format!("{}: {};", name, value)))
+        .collect();
+    Some(style).filter(|style| !style.is_empty())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Size {
     Small,
     Normal,
@@ -44,7 +149,7 @@ impl Size {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Position {
     TopLeft,
     // TopMiddle,
@@ -53,33 +158,120 @@ pub enum Position {
     BottomLeft,
     // BottomMiddle,
     BottomRight,
+
+    /// Top left in a left-to-right layout, top right in a right-to-left one
+    TopStart,
+    TopEnd,
+    BottomStart,
+    BottomEnd,
 }
 
 impl Position {
+    pub const ALL: [Position; 4] = [
+        Position::TopLeft,
+        Position::TopRight,
+        Position::BottomLeft,
+        Position::BottomRight,
+    ];
+
+    /// The physical position for the given layout direction. Logical
+    /// positions (start and end) are mirrored in a right-to-left layout
+    pub fn resolve(self, direction: Direction) -> Position {
+        match (self, direction) {
+            (Position::TopStart, Direction::Ltr) | (Position::TopEnd, Direction::Rtl) => {
+                Position::TopLeft
+            }
+            (Position::TopEnd, Direction::Ltr) | (Position::TopStart, Direction::Rtl) => {
+                Position::TopRight
+            }
+            (Position::BottomStart, Direction::Ltr) | (Position::BottomEnd, Direction::Rtl) => {
+                Position::BottomLeft
+            }
+            (Position::BottomEnd, Direction::Ltr) | (Position::BottomStart, Direction::Rtl) => {
+                Position::BottomRight
+            }
+            (position, _) => position,
+        }
+    }
+
+    /// Whether the position is on the left of the screen, once resolved
+    /// left-to-right
+    fn is_left(&self) -> bool {
+        matches!(
+            self.resolve(Direction::Ltr),
+            Position::TopLeft | Position::BottomLeft
+        )
+    }
+
     pub(crate) fn style(&self) -> &'static str {
-        match self {
+        match self.resolve(Direction::Ltr) {
             Position::TopLeft => "ybn-top-left",
             Position::TopRight => "ybn-top-right",
             Position::BottomLeft => "ybn-bottom-left",
-            Position::BottomRight => "ybn-bottom-right",
+            _ => "ybn-bottom-right",
         }
     }
 
     pub(crate) fn animate_in_style(&self) -> &'static str {
-        match self {
-            Position::TopLeft => "ybn-animate-in-from-left",
-            Position::TopRight => "ybn-animate-in-from-right",
-            Position::BottomLeft => "ybn-animate-in-from-left",
-            Position::BottomRight => "ybn-animate-in-from-right",
+        if self.is_left() {
+            "ybn-animate-in-from-left"
+        } else {
+            "ybn-animate-in-from-right"
         }
     }
 
     pub(crate) fn animate_out_style(&self) -> &'static str {
-        match self {
-            Position::TopLeft => "ybn-animate-out-to-left",
-            Position::TopRight => "ybn-animate-out-to-right",
-            Position::BottomLeft => "ybn-animate-out-to-left",
-            Position::BottomRight => "ybn-animate-out-to-right",
+        if self.is_left() {
+            "ybn-animate-out-to-left"
+        } else {
+            "ybn-animate-out-to-right"
+        }
+    }
+}
+
+/// Layout direction, which decides where logical positions such as
+/// [`Position::TopStart`] end up
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_mirrors_logical_positions() {
+        use Direction::*;
+        use Position::*;
+        let cases = [
+            (TopStart, Ltr, TopLeft),
+            (TopEnd, Ltr, TopRight),
+            (BottomStart, Ltr, BottomLeft),
+            (BottomEnd, Ltr, BottomRight),
+            (TopStart, Rtl, TopRight),
+            (TopEnd, Rtl, TopLeft),
+            (BottomStart, Rtl, BottomRight),
+            (BottomEnd, Rtl, BottomLeft),
+        ];
+        for &(position, direction, expected) in cases.iter() {
+            assert_eq!(
+                position.resolve(direction),
+                expected,
+                "{:?} in {:?}",
+                position,
+                direction
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_keeps_physical_positions() {
+        for &position in Position::ALL.iter() {
+            assert_eq!(position.resolve(Direction::Ltr), position);
+            assert_eq!(position.resolve(Direction::Rtl), position);
         }
     }
 }
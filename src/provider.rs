@@ -0,0 +1,162 @@
+use yew::prelude::*;
+use yew_functional::ContextProvider;
+
+use crate::clock::ClockHandle;
+use crate::collection::NotificationCollection;
+use crate::config::NotificationConfig;
+use crate::{Direction, NotificationAgentInput, NotificationProps};
+
+/// Context provided by a [`NotificationProvider`] to its children.
+/// [`use_notifications`](crate::use_notifications) picks this up
+/// automatically, struct components can get it with `use_context` in a
+/// function component wrapper or by taking it as a property
+#[derive(Clone, PartialEq)]
+pub struct NotificationContext {
+    callback: Callback<NotificationAgentInput>,
+}
+
+impl NotificationContext {
+    /// Send an input to the provider. Only the inputs that spawn, update or
+    /// close notifications are handled, the history and observers are
+    /// features of the [`NotificationAgent`](crate::NotificationAgent)
+    pub fn send(&self, input: NotificationAgentInput) {
+        self.callback.emit(input)
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct NotificationProviderProps {
+    pub children: Children,
+
+    /// See [`NotificationConsumerProps::inline`](crate::NotificationConsumerProps::inline)
+    #[prop_or_default]
+    pub inline: bool,
+
+    /// See [`NotificationConsumerProps::max_visible`](crate::NotificationConsumerProps::max_visible)
+    #[prop_or_default]
+    pub max_visible: Option<usize>,
+
+    /// See [`NotificationConsumerProps::clock`](crate::NotificationConsumerProps::clock)
+    #[prop_or_default]
+    pub clock: ClockHandle,
+
+    /// See [`NotificationConsumerProps::config`](crate::NotificationConsumerProps::config)
+    #[prop_or_default]
+    pub config: NotificationConfig,
+
+    /// See [`NotificationConsumerProps::direction`](crate::NotificationConsumerProps::direction)
+    #[prop_or_default]
+    pub direction: Direction,
+}
+
+/// Holds notifications in a Yew context, as an alternative to the
+/// [`NotificationAgent`](crate::NotificationAgent) and
+/// [`NotificationConsumer`](crate::NotificationConsumer). Notifications
+/// spawned by children of the provider are rendered by the nearest provider,
+/// so providers can be nested for sub-applications
+pub struct NotificationProvider {
+    props: NotificationProviderProps,
+    link: ComponentLink<Self>,
+    context: NotificationContext,
+    notifications: NotificationCollection,
+}
+
+pub enum Msg {
+    Input(NotificationAgentInput),
+
+    Closed(usize, Option<Callback<()>>),
+    TimedOut(usize, Option<Callback<()>>),
+}
+
+impl Component for NotificationProvider {
+    type Message = Msg;
+    type Properties = NotificationProviderProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let context = NotificationContext {
+            callback: link.callback(Msg::Input),
+        };
+        let notifications = NotificationCollection::new(
+            props.max_visible,
+            props.clock.clone(),
+            props.config.clone(),
+            props.direction,
+        );
+        Self {
+            props,
+            link,
+            context,
+            notifications,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.notifications.set_max_visible(props.max_visible);
+            self.notifications.set_clock(props.clock.clone());
+            self.notifications.set_config(props.config.clone());
+            self.notifications.set_direction(props.direction);
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(input) => match input {
+                NotificationAgentInput::New(props)
+                | NotificationAgentInput::NewInScope(props, _) => {
+                    self.spawn(props, None);
+                    true
+                }
+                NotificationAgentInput::NewTagged(props, tag)
+                | NotificationAgentInput::NewTaggedInScope(props, tag, _) => {
+                    self.spawn(props, Some(tag));
+                    true
+                }
+                NotificationAgentInput::UpdateTagged(mut props, tag) => {
+                    props.standalone = false;
+                    self.notifications.update_tag(
+                        &self.link,
+                        props,
+                        &tag,
+                        Msg::Closed,
+                        Msg::TimedOut,
+                    );
+                    true
+                }
+                NotificationAgentInput::CloseTagged(tag) => {
+                    self.notifications.remove_tag(&tag);
+                    true
+                }
+                _ => false,
+            },
+            Msg::Closed(id, callback) | Msg::TimedOut(id, callback) => {
+                self.notifications.remove(id);
+                if let Some(callback) = callback {
+                    callback.emit(());
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <ContextProvider<NotificationContext> context={self.context.clone()}>
+                {self.props.children.clone()}
+                {self.notifications.view(self.props.inline)}
+            </ContextProvider<NotificationContext>>
+        }
+    }
+}
+
+impl NotificationProvider {
+    fn spawn(&mut self, mut props: NotificationProps, tag: Option<String>) {
+        props.standalone = false;
+        self.notifications
+            .spawn(&self.link, props, tag, Msg::Closed, Msg::TimedOut);
+    }
+}
@@ -1,7 +1,16 @@
+use std::cell::RefCell;
+
 use yew::prelude::*;
 
 use crate::agent::{NotificationAgent, NotificationAgentInput};
+use crate::builder::NotificationBuilder;
 use crate::notification::NotificationProps;
+use crate::Color;
+
+thread_local! {
+    /// Service for integrations that have no component to hold one
+    static SHARED: RefCell<Option<NotificationService>> = RefCell::new(None);
+}
 
 pub struct NotificationService {
     dispatcher: yew::agent::Dispatcher<NotificationAgent>,
@@ -21,21 +30,83 @@ impl NotificationService {
     }
 
     pub fn spawn(&mut self, props: NotificationProps) {
-        self.dispatcher.send(NotificationAgentInput::New(props))
+        self.send(NotificationAgentInput::New(props))
     }
 
     /// Spawn a notification with a specific id, so that it can be manually closed.
-    /// It is the user's responsibility to use unique id's.
-    /// TODO: don't force responsibility onto the user?
+    /// Spawning with the id of a notification that is still displayed replaces it.
     pub fn spawn_with_id(&mut self, props: NotificationProps, id: String) {
-        self.dispatcher
-            .send(NotificationAgentInput::NewTagged(props, id))
+        self.send(NotificationAgentInput::NewTagged(props, id))
+    }
+
+    /// Spawn a notification in a [`NotificationConsumer`](crate::NotificationConsumer)
+    /// registered with the given scope, or the default consumer if no such
+    /// consumer is mounted
+    pub fn spawn_in_scope(&mut self, props: NotificationProps, scope: String) {
+        self.send(NotificationAgentInput::NewInScope(props, scope))
+    }
+
+    /// Combination of [`spawn_with_id`](Self::spawn_with_id) and
+    /// [`spawn_in_scope`](Self::spawn_in_scope)
+    pub fn spawn_with_id_in_scope(&mut self, props: NotificationProps, id: String, scope: String) {
+        self.send(NotificationAgentInput::NewTaggedInScope(props, id, scope))
+    }
+
+    /// Replace the properties of a notification spawned with
+    /// [`spawn_with_id`](Self::spawn_with_id), if it is still displayed
+    pub fn update_id(&mut self, props: NotificationProps, id: String) {
+        self.send(NotificationAgentInput::UpdateTagged(props, id))
     }
 
     pub fn close_id(&mut self, id: String) {
-        self.dispatcher
-            .send(NotificationAgentInput::CloseTagged(id))
+        self.send(NotificationAgentInput::CloseTagged(id))
+    }
+
+    pub(crate) fn send(&mut self, input: NotificationAgentInput) {
+        self.dispatcher.send(input)
+    }
+
+    /// Run `f` with a service shared by the whole thread, created on first
+    /// use. Does nothing if called from within `f`, e.g. by a logger that
+    /// records something the dispatcher logs
+    pub(crate) fn with_shared(f: impl FnOnce(&mut NotificationService)) {
+        SHARED.with(|shared| {
+            if let Ok(mut shared) = shared.try_borrow_mut() {
+                f(shared.get_or_insert_with(NotificationService::new))
+            }
+        })
     }
 
     // Helper methods
+
+    /// Spawn a [`Color::Success`] notification with a header and a body of
+    /// text or [`Html`]
+    pub fn success(&mut self, header: impl Into<String>, body: impl Into<Html>) {
+        self.spawn_text(Color::Success, header.into(), body.into())
+    }
+
+    /// Spawn a [`Color::Info`] notification, see [`success`](Self::success)
+    pub fn info(&mut self, header: impl Into<String>, body: impl Into<Html>) {
+        self.spawn_text(Color::Info, header.into(), body.into())
+    }
+
+    /// Spawn a [`Color::Warning`] notification, see [`success`](Self::success)
+    pub fn warning(&mut self, header: impl Into<String>, body: impl Into<Html>) {
+        self.spawn_text(Color::Warning, header.into(), body.into())
+    }
+
+    /// Spawn a [`Color::Danger`] notification, see [`success`](Self::success)
+    pub fn error(&mut self, header: impl Into<String>, body: impl Into<Html>) {
+        self.spawn_text(Color::Danger, header.into(), body.into())
+    }
+
+    fn spawn_text(&mut self, color: Color, header: String, body: Html) {
+        self.spawn(
+            NotificationBuilder::new()
+                .header(header)
+                .body(body)
+                .color(color)
+                .build(),
+        )
+    }
 }
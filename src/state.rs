@@ -0,0 +1,533 @@
+//! Framework-agnostic notification state.
+//!
+//! Nothing in this module depends on yew or web-sys, and time is always passed
+//! in explicitly as `now` (a [`Duration`] since an arbitrary epoch), so the
+//! ordering, queueing, deduplication and timeout behaviour of notifications
+//! can be exercised natively. The components in this crate only render it.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::Position;
+
+/// How a notification left the screen
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NotificationOutcome {
+    /// The user closed the notification, or it was closed by its tag
+    Closed,
+    /// The notification's timeout elapsed without any interaction
+    TimedOut,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    /// Animating onto the screen
+    Entering,
+    Visible,
+    /// Animating off the screen, for the given reason
+    Leaving(NotificationOutcome),
+    /// Off the screen, ready to be removed
+    Gone(NotificationOutcome),
+}
+
+/// State machine for a single notification
+///
+/// ```text
+/// Entering --shown--> Visible
+///    |                   |
+///    +---close/expire----+--> Leaving(outcome) --finish--> Gone(outcome)
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct NotificationState {
+    phase: Phase,
+    deadline: Option<Duration>,
+    /// Time left before the deadline while the timeout is paused
+    paused: Option<Duration>,
+}
+
+impl NotificationState {
+    pub fn new(now: Duration, timeout: Option<Duration>) -> Self {
+        Self {
+            phase: Phase::Entering,
+            deadline: timeout.map(|timeout| now + timeout),
+            paused: None,
+        }
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Time at which the notification times out, if it has a timeout that
+    /// isn't paused
+    pub fn deadline(&self) -> Option<Duration> {
+        if self.paused.is_some() {
+            None
+        } else {
+            self.deadline
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+
+    /// Stop the timeout from running out, e.g. while the user reads the
+    /// notification. Returns `false` if there is no timeout or it is already
+    /// paused
+    pub fn pause(&mut self, now: Duration) -> bool {
+        match self.deadline {
+            Some(deadline) if self.paused.is_none() => {
+                self.paused = Some(deadline.saturating_sub(now));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Restart a paused timeout with the time that was left. Returns that
+    /// time, or `None` if the timeout wasn't paused
+    pub fn resume(&mut self, now: Duration) -> Option<Duration> {
+        let remaining = self.paused.take()?;
+        self.deadline = Some(now + remaining);
+        Some(remaining)
+    }
+
+    /// The notification was replaced with one that has another timeout:
+    /// restart it from `now`. A notification that was leaving the screen
+    /// comes back, as its replacement should be displayed
+    pub fn restart(&mut self, now: Duration, timeout: Option<Duration>) {
+        let phase = match self.phase {
+            Phase::Visible => Phase::Visible,
+            _ => Phase::Entering,
+        };
+        *self = Self::new(now, timeout);
+        self.phase = phase;
+    }
+
+    /// Whether the notification is leaving or has left the screen
+    pub fn is_leaving(&self) -> bool {
+        matches!(self.phase, Phase::Leaving(_) | Phase::Gone(_))
+    }
+
+    /// The entrance animation finished. Returns `true` the first time only
+    pub fn shown(&mut self) -> bool {
+        if self.phase == Phase::Entering {
+            self.phase = Phase::Visible;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The user closed the notification. Returns `false` if it was already leaving
+    pub fn close(&mut self) -> bool {
+        self.leave(NotificationOutcome::Closed)
+    }
+
+    /// The notification's timer fired. Returns `false` if it was already leaving
+    pub fn expire(&mut self) -> bool {
+        self.leave(NotificationOutcome::TimedOut)
+    }
+
+    /// Expire the notification if its deadline has passed. Returns `true` if
+    /// the notification started leaving
+    pub fn tick(&mut self, now: Duration) -> bool {
+        match self.deadline() {
+            Some(deadline) if now >= deadline => self.expire(),
+            _ => false,
+        }
+    }
+
+    /// The exit animation finished. Returns the outcome the first time only,
+    /// after which the notification can be removed
+    pub fn finish(&mut self) -> Option<NotificationOutcome> {
+        match self.phase {
+            Phase::Leaving(outcome) => {
+                self.phase = Phase::Gone(outcome);
+                Some(outcome)
+            }
+            _ => None,
+        }
+    }
+
+    fn leave(&mut self, outcome: NotificationOutcome) -> bool {
+        if self.is_leaving() {
+            false
+        } else {
+            self.phase = Phase::Leaving(outcome);
+            true
+        }
+    }
+}
+
+/// A notification held by a [`NotificationStack`]
+#[derive(Clone, PartialEq, Debug)]
+pub struct Entry<T> {
+    pub id: usize,
+    pub tag: Option<String>,
+    pub position: Position,
+    pub payload: T,
+}
+
+/// Ordered notifications, with at most `max_visible` displayed per position.
+/// Notifications beyond that are queued and displayed in order as others are
+/// removed. Tags are unique: pushing a notification with the tag of one that
+/// is displayed or queued replaces it in place.
+#[derive(Clone, Debug)]
+pub struct NotificationStack<T> {
+    next_id: usize,
+    max_visible: Option<usize>,
+    visible: Vec<Entry<T>>,
+    queued: VecDeque<Entry<T>>,
+}
+
+impl<T> Default for NotificationStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> NotificationStack<T> {
+    pub fn new() -> Self {
+        Self {
+            next_id: 0,
+            max_visible: None,
+            visible: Vec::new(),
+            queued: VecDeque::new(),
+        }
+    }
+
+    /// Limit the number of notifications displayed at each position
+    pub fn with_max_visible(max_visible: Option<usize>) -> Self {
+        Self {
+            max_visible,
+            ..Self::new()
+        }
+    }
+
+    pub fn set_max_visible(&mut self, max_visible: Option<usize>) {
+        self.max_visible = max_visible;
+        for position in Position::ALL.iter() {
+            self.promote(*position);
+        }
+    }
+
+    /// Add a notification, building its payload from the id it is assigned.
+    /// If a notification with the same tag exists, its payload is replaced
+    /// and its id reused. Returns the id
+    pub fn push_with(
+        &mut self,
+        position: Position,
+        tag: Option<String>,
+        payload: impl FnOnce(usize) -> T,
+    ) -> usize {
+        if let Some(entry) = tag.as_deref().and_then(|tag| self.find_tag_mut(tag)) {
+            entry.payload = payload(entry.id);
+            return entry.id;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.insert(Entry {
+            id,
+            tag,
+            position,
+            payload: payload(id),
+        });
+        id
+    }
+
+    /// Replace the payload of the notification with the given tag, building
+    /// it from its id. Returns the id, or `None` if there is no such notification
+    pub fn replace_tag_with(
+        &mut self,
+        tag: &str,
+        payload: impl FnOnce(usize) -> T,
+    ) -> Option<usize> {
+        let entry = self.find_tag_mut(tag)?;
+        entry.payload = payload(entry.id);
+        Some(entry.id)
+    }
+
+    /// Remove a notification, displaying the next queued notification at
+    /// its position
+    pub fn remove(&mut self, id: usize) -> Option<Entry<T>> {
+        if let Some(index) = self.visible.iter().position(|entry| entry.id == id) {
+            let entry = self.visible.remove(index);
+            self.promote(entry.position);
+            Some(entry)
+        } else {
+            let index = self.queued.iter().position(|entry| entry.id == id)?;
+            self.queued.remove(index)
+        }
+    }
+
+    pub fn remove_tag(&mut self, tag: &str) -> Option<Entry<T>> {
+        let id = self.find_tag(tag)?.id;
+        self.remove(id)
+    }
+
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut Entry<T>> {
+        self.visible
+            .iter_mut()
+            .chain(self.queued.iter_mut())
+            .find(|entry| entry.id == id)
+    }
+
+    pub fn find_tag(&self, tag: &str) -> Option<&Entry<T>> {
+        self.visible
+            .iter()
+            .chain(self.queued.iter())
+            .find(|entry| entry.tag.as_deref() == Some(tag))
+    }
+
+    fn find_tag_mut(&mut self, tag: &str) -> Option<&mut Entry<T>> {
+        self.visible
+            .iter_mut()
+            .chain(self.queued.iter_mut())
+            .find(|entry| entry.tag.as_deref() == Some(tag))
+    }
+
+    /// Displayed notifications, in the order they were added
+    pub fn visible(&self) -> impl Iterator<Item = &Entry<T>> {
+        self.visible.iter()
+    }
+
+    /// Displayed notifications at the given position, in the order they were added
+    pub fn visible_at(&self, position: Position) -> impl Iterator<Item = &Entry<T>> {
+        self.visible
+            .iter()
+            .filter(move |entry| entry.position == position)
+    }
+
+    pub fn queued(&self) -> impl Iterator<Item = &Entry<T>> {
+        self.queued.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.visible.is_empty() && self.queued.is_empty()
+    }
+
+    fn insert(&mut self, entry: Entry<T>) {
+        if self.has_room(entry.position) {
+            self.visible.push(entry);
+        } else {
+            self.queued.push_back(entry);
+        }
+    }
+
+    fn has_room(&self, position: Position) -> bool {
+        match self.max_visible {
+            Some(max) => self.visible_at(position).count() < max,
+            None => true,
+        }
+    }
+
+    /// Move queued notifications at the position into view while there's room
+    fn promote(&mut self, position: Position) {
+        while self.has_room(position) {
+            match self
+                .queued
+                .iter()
+                .position(|entry| entry.position == position)
+            {
+                Some(index) => {
+                    let entry = self.queued.remove(index).expect("index is in bounds");
+                    self.visible.push(entry);
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::clock::{Clock, ManualClock};
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    fn ids<'a, T: 'a>(entries: impl Iterator<Item = &'a Entry<T>>) -> Vec<usize> {
+        entries.map(|entry| entry.id).collect()
+    }
+
+    #[test]
+    fn stack_keeps_push_order() {
+        let mut stack = NotificationStack::new();
+        let a = stack.push_with(Position::TopRight, None, |_| "a");
+        let b = stack.push_with(Position::BottomLeft, None, |_| "b");
+        let c = stack.push_with(Position::TopRight, None, |_| "c");
+
+        assert_eq!(ids(stack.visible()), vec![a, b, c]);
+        assert_eq!(ids(stack.visible_at(Position::TopRight)), vec![a, c]);
+        assert_eq!(ids(stack.visible_at(Position::BottomLeft)), vec![b]);
+    }
+
+    #[test]
+    fn stack_queues_beyond_max_visible_per_position() {
+        let mut stack = NotificationStack::with_max_visible(Some(2));
+        let a = stack.push_with(Position::TopRight, None, |_| "a");
+        let b = stack.push_with(Position::TopRight, None, |_| "b");
+        let c = stack.push_with(Position::TopRight, None, |_| "c");
+        let d = stack.push_with(Position::TopRight, None, |_| "d");
+        let e = stack.push_with(Position::BottomLeft, None, |_| "e");
+
+        assert_eq!(ids(stack.visible()), vec![a, b, e]);
+        assert_eq!(ids(stack.queued()), vec![c, d]);
+
+        stack.remove(a);
+        assert_eq!(ids(stack.visible_at(Position::TopRight)), vec![b, c]);
+        assert_eq!(ids(stack.queued()), vec![d]);
+
+        // Room at another position doesn't promote
+        stack.remove(e);
+        assert_eq!(ids(stack.queued()), vec![d]);
+
+        stack.set_max_visible(None);
+        assert_eq!(ids(stack.visible_at(Position::TopRight)), vec![b, c, d]);
+        assert!(stack.queued().next().is_none());
+    }
+
+    #[test]
+    fn stack_reuses_tags() {
+        let mut stack = NotificationStack::new();
+        let a = stack.push_with(Position::TopRight, Some("upload".into()), |_| "started");
+        let b = stack.push_with(Position::TopLeft, None, |_| "other");
+        let again = stack.push_with(Position::BottomLeft, Some("upload".into()), |_| "done");
+
+        assert_eq!(again, a);
+        assert_eq!(ids(stack.visible()), vec![a, b]);
+        let entry = stack.find_tag("upload").unwrap();
+        assert_eq!(entry.payload, "done");
+        assert_eq!(entry.position, Position::TopRight);
+
+        assert_eq!(stack.replace_tag_with("upload", |_| "checked"), Some(a));
+        assert_eq!(stack.find_tag("upload").unwrap().payload, "checked");
+        assert_eq!(stack.replace_tag_with("missing", |_| "x"), None);
+    }
+
+    #[test]
+    fn stack_removes_queued_tag() {
+        let mut stack = NotificationStack::with_max_visible(Some(1));
+        let a = stack.push_with(Position::TopRight, None, |_| "a");
+        let b = stack.push_with(Position::TopRight, Some("sync".into()), |_| "b");
+        let c = stack.push_with(Position::TopRight, None, |_| "c");
+
+        assert_eq!(stack.remove_tag("sync").map(|entry| entry.id), Some(b));
+        assert_eq!(ids(stack.visible()), vec![a]);
+        assert_eq!(ids(stack.queued()), vec![c]);
+        assert!(stack.remove_tag("sync").is_none());
+
+        stack.remove(a);
+        stack.remove(c);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn state_closes_once() {
+        let mut state = NotificationState::new(secs(0), None);
+        assert_eq!(state.phase(), Phase::Entering);
+        assert!(state.shown());
+        assert!(!state.shown());
+        assert_eq!(state.phase(), Phase::Visible);
+
+        assert!(state.close());
+        assert!(!state.close());
+        assert!(!state.expire());
+        assert_eq!(state.phase(), Phase::Leaving(NotificationOutcome::Closed));
+
+        assert_eq!(state.finish(), Some(NotificationOutcome::Closed));
+        assert_eq!(state.finish(), None);
+        assert_eq!(state.phase(), Phase::Gone(NotificationOutcome::Closed));
+    }
+
+    #[test]
+    fn state_expires_while_entering() {
+        let mut state = NotificationState::new(secs(0), Some(secs(1)));
+        assert!(state.expire());
+        assert!(!state.shown());
+        assert!(!state.close());
+        assert_eq!(state.finish(), Some(NotificationOutcome::TimedOut));
+    }
+
+    #[test]
+    fn state_finish_requires_leaving() {
+        let mut state = NotificationState::new(secs(0), None);
+        assert_eq!(state.finish(), None);
+        assert!(!state.is_leaving());
+    }
+
+    #[test]
+    fn state_ticks_to_deadline() {
+        let mut state = NotificationState::new(secs(10), Some(secs(5)));
+        assert_eq!(state.deadline(), Some(secs(15)));
+        assert!(!state.tick(secs(14)));
+        assert!(state.tick(secs(15)));
+        assert!(!state.tick(secs(16)));
+        assert_eq!(state.phase(), Phase::Leaving(NotificationOutcome::TimedOut));
+
+        let mut sticky = NotificationState::new(secs(0), None);
+        assert!(!sticky.tick(secs(1000)));
+    }
+
+    #[test]
+    fn state_restarts_with_new_timeout() {
+        let clock = ManualClock::new();
+        let state = Rc::new(RefCell::new(NotificationState::new(clock.now(), None)));
+        state.borrow_mut().shown();
+        clock.advance(secs(60));
+
+        // A sticky notification updated with a timeout, scheduled like the component does
+        state.borrow_mut().restart(clock.now(), Some(secs(3)));
+        assert_eq!(state.borrow().phase(), Phase::Visible);
+        let _timer = {
+            let state = state.clone();
+            clock.schedule(
+                secs(3),
+                Box::new(move || {
+                    state.borrow_mut().expire();
+                }),
+            )
+        };
+
+        clock.advance(secs(2));
+        assert!(!state.borrow().is_leaving());
+        clock.advance(secs(1));
+        assert_eq!(
+            state.borrow().phase(),
+            Phase::Leaving(NotificationOutcome::TimedOut)
+        );
+    }
+
+    #[test]
+    fn state_restart_brings_back_leaving() {
+        let mut state = NotificationState::new(secs(0), Some(secs(1)));
+        assert!(state.close());
+        state.restart(secs(5), None);
+        assert_eq!(state.phase(), Phase::Entering);
+        assert_eq!(state.deadline(), None);
+        assert_eq!(state.finish(), None);
+    }
+
+    #[test]
+    fn state_pause_keeps_remaining_time() {
+        let mut state = NotificationState::new(secs(0), Some(secs(5)));
+        assert!(state.pause(secs(2)));
+        assert!(!state.pause(secs(3)));
+        assert!(state.is_paused());
+        assert_eq!(state.deadline(), None);
+        assert!(!state.tick(secs(100)));
+
+        assert_eq!(state.resume(secs(100)), Some(secs(3)));
+        assert_eq!(state.resume(secs(100)), None);
+        assert!(!state.tick(secs(102)));
+        assert!(state.tick(secs(103)));
+    }
+}
@@ -0,0 +1,222 @@
+//! Styles injected into the document at runtime, for applications that don't
+//! compile `index.scss` with Sass.
+//!
+//! Every [`NotificationConsumer`](crate::NotificationConsumer) and
+//! [`NotificationProvider`](crate::NotificationProvider) adds the `ybn-*`
+//! rules to a single `<style id="ybn-styles">` element in the document head,
+//! configured by [`NotificationConfig::with_styles`](crate::NotificationConfig::with_styles).
+//! With several consumers, the config of the last one mounted or changed wins.
+
+use std::time::Duration;
+
+const STYLE_ID: &str = "ybn-styles";
+
+/// Sizes and animation durations of the injected styles. The defaults match
+/// those of `index.scss`
+///
+/// ```ignore
+/// let config = NotificationConfig::new().with_styles(
+///     StyleConfig::new()
+///         .max_width("400px")
+///         .animate_in(Duration::from_millis(300)),
+/// );
+/// html! { <NotificationConsumer config={config} /> }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct StyleConfig {
+    max_width: String,
+    gap: String,
+    animate_in: Duration,
+    animate_out: Duration,
+}
+
+impl Default for StyleConfig {
+    fn default() -> Self {
+        Self {
+            max_width: "30vw".to_owned(),
+            gap: "1.5em".to_owned(),
+            animate_in: Duration::from_millis(750),
+            animate_out: Duration::from_millis(200),
+        }
+    }
+}
+
+impl StyleConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum width of a notification (a CSS length)
+    pub fn max_width(mut self, max_width: impl Into<String>) -> Self {
+        self.max_width = max_width.into();
+        self
+    }
+
+    /// Space between notifications and from the edges of the screen (a CSS
+    /// length)
+    pub fn gap(mut self, gap: impl Into<String>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+
+    /// How long a notification takes to slide in. Making room for it in the
+    /// stack takes two thirds of that, and it starts sliding once that is a
+    /// little under half done
+    pub fn animate_in(mut self, duration: Duration) -> Self {
+        self.animate_in = duration;
+        self
+    }
+
+    /// How long a notification takes to slide out. The space it took up is
+    /// closed afterwards
+    pub fn animate_out(mut self, duration: Duration) -> Self {
+        self.animate_out = duration;
+        self
+    }
+
+    /// The stylesheet for this config
+    pub(crate) fn css(&self) -> String {
+        let animate_in = self.animate_in.as_millis();
+        let animate_out = self.animate_out.as_millis();
+        let position = |vertical: &str, horizontal: &str| {
+            format!(
+                ".ybn-{v}-{h} {{ overflow: hidden; position: absolute; {v}: {gap}; {h}: {gap}; }}\n",
+                v = vertical,
+                h = horizontal,
+                gap = self.gap,
+            )
+        };
+        let animate_in_from = |side: &str, offset: &str| {
+            format!(
+                ".ybn-animate-in-from-{side} {{ margin-bottom: 0; max-height: 0; opacity: 0; \
+                 transform: translateX({offset}); \
+                 animation: openSpace {open}ms ease forwards, moveIn {in_}ms {delay}ms ease forwards; }}\n",
+                side = side,
+                offset = offset,
+                open = animate_in * 2 / 3,
+                in_ = animate_in,
+                delay = animate_in * 4 / 15,
+            )
+        };
+        let animate_out_to = |side: &str, keyframes: &str| {
+            format!(
+                ".ybn-animate-out-to-{side} {{ \
+                 animation: {keyframes} {out}ms ease forwards, closeSpace {close}ms {delay}ms ease forwards; }}\n",
+                side = side,
+                keyframes = keyframes,
+                out = animate_out,
+                close = animate_out * 5 / 4,
+                delay = animate_out * 5 / 2,
+            )
+        };
+
+        let mut css = format!(".ybn-body {{ max-width: {}; }}\n", self.max_width);
+        css += &position("top", "left");
+        css += &position("top", "right");
+        css += &position("bottom", "left");
+        css += &position("bottom", "right");
+        css += &animate_in_from("left", "-300px");
+        css += &animate_in_from("right", "300px");
+        css += &animate_out_to("left", "moveOutLeft");
+        css += &animate_out_to("right", "moveOutRight");
+        css += &format!(
+            "@keyframes openSpace {{ to {{ margin-bottom: {}; max-height: unset; }} }}\n",
+            self.gap
+        );
+        css += &format!(
+            ".ybn-center-content {{ max-height: 60vh; max-width: {}; overflow-y: auto; }}\n",
+            self.max_width
+        );
+        css += STATIC_CSS;
+        css
+    }
+
+    /// Add the stylesheet to the document head, or replace the one added
+    /// before
+    pub(crate) fn inject(&self) {
+        let document = match web_sys::window().and_then(|window| window.document()) {
+            Some(document) => document,
+            None => return,
+        };
+        let style = match document.get_element_by_id(STYLE_ID) {
+            Some(style) => style,
+            None => {
+                let style = match document.create_element("style") {
+                    Ok(style) => style,
+                    Err(_) => return,
+                };
+                style.set_id(STYLE_ID);
+                if let Some(head) = document.head() {
+                    let _ = head.append_child(&style);
+                }
+                style
+            }
+        };
+        let css = self.css();
+        if style.text_content().as_deref() != Some(css.as_str()) {
+            style.set_text_content(Some(&css));
+        }
+    }
+}
+
+/// Rules that don't depend on the config
+const STATIC_CSS: &str = "\
+@keyframes closeSpace { to { max-height: 0; } }
+@keyframes moveIn { to { opacity: 1; transform: translateX(0); } }
+@keyframes moveOutLeft { to { opacity: 0; transform: translateX(-300px); } }
+@keyframes moveOutRight { to { opacity: 0; transform: translateX(300px); } }
+.ybn-center .message { opacity: 0.8; }
+.ybn-center .message.ybn-unread { opacity: 1; }
+.ybn-badge { position: relative; }
+.ybn-badge .ybn-badge-count { position: absolute; top: -0.25em; right: -0.25em; font-size: 0.65rem; }
+.ybn-inline { overflow: hidden; }
+.ybn-inline .ybn-body { max-width: none; }
+.ybn-context { margin-top: 0.5em; font-size: 0.85em; list-style: none; }
+.ybn-details { margin-top: 0.5em; }
+.ybn-details pre { max-height: 12em; overflow: auto; white-space: pre-wrap; word-break: break-word; }
+.ybn-content { overflow: hidden; }
+.ybn-content.ybn-truncated { \
+-webkit-mask-image: linear-gradient(to bottom, black 60%, transparent); \
+mask-image: linear-gradient(to bottom, black 60%, transparent); }
+.ybn-icon { margin-inline-end: 0.5em; vertical-align: middle; }
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_css_matches_index_scss() {
+        let css = StyleConfig::default().css();
+        for rule in [
+            ".ybn-body { max-width: 30vw; }",
+            ".ybn-top-left { overflow: hidden; position: absolute; top: 1.5em; left: 1.5em; }",
+            ".ybn-bottom-right { overflow: hidden; position: absolute; bottom: 1.5em; right: 1.5em; }",
+            "animation: openSpace 500ms ease forwards, moveIn 750ms 200ms ease forwards;",
+            ".ybn-animate-out-to-left { animation: moveOutLeft 200ms ease forwards, closeSpace 250ms 500ms ease forwards; }",
+            "@keyframes openSpace { to { margin-bottom: 1.5em; max-height: unset; } }",
+            ".ybn-center-content { max-height: 60vh; max-width: 30vw; overflow-y: auto; }",
+        ]
+        .iter()
+        {
+            assert!(css.contains(rule), "missing {:?} in\n{}", rule, css);
+        }
+    }
+
+    #[test]
+    fn css_uses_config() {
+        let css = StyleConfig::new()
+            .max_width("400px")
+            .gap("1em")
+            .animate_in(Duration::from_millis(300))
+            .animate_out(Duration::from_millis(100))
+            .css();
+        assert!(css.contains(".ybn-body { max-width: 400px; }"));
+        assert!(css.contains("top: 1em; right: 1em;"));
+        assert!(css.contains("openSpace 200ms ease forwards, moveIn 300ms 80ms ease forwards"));
+        assert!(
+            css.contains("moveOutRight 100ms ease forwards, closeSpace 125ms 250ms ease forwards")
+        );
+        assert!(!css.contains("30vw"));
+    }
+}
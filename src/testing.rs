@@ -0,0 +1,151 @@
+//! Helpers for testing components that spawn notifications, without rendering
+//! a [`NotificationConsumer`](crate::NotificationConsumer) or inspecting the DOM.
+//!
+//! ```ignore
+//! let recorder = RecordingConsumer::new();
+//! // ... submit an invalid form
+//! recorder.assert_notified(Color::Danger, "Invalid");
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use yew::worker::{Bridge, Bridged};
+
+use crate::{
+    Color, NotificationAgent, NotificationAgentInput, NotificationAgentOutput, NotificationProps,
+};
+
+/// A notification received by a [`RecordingConsumer`]
+#[derive(Clone, PartialEq)]
+pub struct RecordedNotification {
+    /// The id the notification was spawned or updated with, if any
+    pub tag: Option<String>,
+    pub props: NotificationProps,
+    /// Whether this was an update of a notification spawned earlier
+    pub updated: bool,
+}
+
+impl RecordedNotification {
+    pub fn header(&self) -> Option<&str> {
+        self.props.header.as_deref()
+    }
+
+    pub fn color(&self) -> Color {
+        self.props.color.clone()
+    }
+}
+
+/// Registers with the [`NotificationAgent`] as a recorder, recording
+/// everything spawned through a [`NotificationService`](crate::NotificationService)
+/// in any scope, whether or not a
+/// [`NotificationConsumer`](crate::NotificationConsumer) renders it.
+/// Notifications are recorded for as long as the recorder is alive
+pub struct RecordingConsumer {
+    _bridge: Box<dyn Bridge<NotificationAgent>>,
+    recorded: Rc<RefCell<Vec<RecordedNotification>>>,
+    closed: Rc<RefCell<Vec<String>>>,
+}
+
+impl Default for RecordingConsumer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecordingConsumer {
+    pub fn new() -> Self {
+        let recorded = Rc::new(RefCell::new(Vec::new()));
+        let closed = Rc::new(RefCell::new(Vec::new()));
+        let mut bridge = {
+            let recorded = recorded.clone();
+            let closed = closed.clone();
+            NotificationAgent::bridge(yew::Callback::from(move |output| {
+                let notification = match output {
+                    NotificationAgentOutput::New(props) => RecordedNotification {
+                        tag: None,
+                        props,
+                        updated: false,
+                    },
+                    NotificationAgentOutput::NewTagged(props, tag) => RecordedNotification {
+                        tag: Some(tag),
+                        props,
+                        updated: false,
+                    },
+                    NotificationAgentOutput::UpdateTagged(props, tag) => RecordedNotification {
+                        tag: Some(tag),
+                        props,
+                        updated: true,
+                    },
+                    NotificationAgentOutput::CloseTagged(tag) => {
+                        closed.borrow_mut().push(tag);
+                        return;
+                    }
+                    NotificationAgentOutput::History(_) | NotificationAgentOutput::Event(_) => {
+                        return
+                    }
+                };
+                recorded.borrow_mut().push(notification);
+            }))
+        };
+        bridge.send(NotificationAgentInput::RegisterRecorder);
+        Self {
+            _bridge: bridge,
+            recorded,
+            closed,
+        }
+    }
+
+    /// Notifications recorded so far, in the order they were received
+    pub fn notifications(&self) -> Vec<RecordedNotification> {
+        self.recorded.borrow().clone()
+    }
+
+    /// Remove and return the notifications recorded so far
+    pub fn take_notifications(&self) -> Vec<RecordedNotification> {
+        self.recorded.borrow_mut().drain(..).collect()
+    }
+
+    /// Ids of the notifications closed with
+    /// [`NotificationService::close_id`](crate::NotificationService::close_id)
+    pub fn closed_ids(&self) -> Vec<String> {
+        self.closed.borrow().clone()
+    }
+
+    /// Panic unless a notification with the given color and a header
+    /// containing `header_contains` has been recorded
+    pub fn assert_notified(&self, color: Color, header_contains: &str) {
+        let recorded = self.recorded.borrow();
+        let found = recorded.iter().any(|notification| {
+            notification.color() == color
+                && matches!(notification.header(), Some(header) if header.contains(header_contains))
+        });
+        if !found {
+            panic!(
+                "expected a {:?} notification with a header containing {:?}, recorded: {:?}",
+                color,
+                header_contains,
+                summary(&recorded)
+            );
+        }
+    }
+
+    /// Panic if any notification has been recorded
+    pub fn assert_no_notifications(&self) {
+        let recorded = self.recorded.borrow();
+        if !recorded.is_empty() {
+            panic!(
+                "expected no notifications, recorded: {:?}",
+                summary(&recorded)
+            );
+        }
+    }
+}
+
+/// Color and header of each notification, for assertion messages
+fn summary(recorded: &[RecordedNotification]) -> Vec<String> {
+    recorded
+        .iter()
+        .map(|notification| format!("{:?} {:?}", notification.color(), notification.header()))
+        .collect()
+}
@@ -0,0 +1,142 @@
+//! CSS classes for the elements rendered by this crate.
+//!
+//! The components only use the classes of the installed [`Theme`] for
+//! styling, apart from the `ybn-*` classes for positioning and animation.
+//! [`BulmaTheme`] is used unless another theme is installed with [`set_theme`]
+//! before mounting any components:
+//!
+//! ```ignore
+//! struct Tailwind;
+//!
+//! impl Theme for Tailwind {
+//!     fn part(&self, part: Part) -> Classes {
+//!         match part {
+//!             Part::Message => Classes::from("rounded shadow bg-white"),
+//!             Part::MessageHeader => Classes::from("flex justify-between font-bold p-2"),
+//!             // ...
+//!             _ => Classes::new(),
+//!         }
+//!     }
+//!
+//!     fn color(&self, color: &Color) -> Classes {
+//!         match color {
+//!             Color::Danger => Classes::from("bg-red-100 text-red-900"),
+//!             // ...
+//!             _ => Classes::new(),
+//!         }
+//!     }
+//!
+//!     fn size(&self, _size: Size) -> Classes {
+//!         Classes::new()
+//!     }
+//! }
+//!
+//! set_theme(Tailwind);
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use yew::Classes;
+
+use crate::{Color, Size};
+
+thread_local! {
+    static THEME: RefCell<Rc<dyn Theme>> = RefCell::new(Rc::new(BulmaTheme));
+}
+
+/// Structural elements of the components
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Part {
+    /// A notification, or an entry in the notification center
+    Message,
+    MessageHeader,
+    MessageBody,
+    /// A notification's close button
+    Close,
+    /// Container of a row of buttons
+    Buttons,
+    /// An action button
+    Button,
+    /// A less prominent button, e.g. to show more of the body
+    TextButton,
+    /// Container of a [`crate::Icon`]
+    Icon,
+    /// Count of unread or missed notifications
+    Count,
+    /// The button of a [`NotificationBadge`](crate::NotificationBadge)
+    BadgeButton,
+    /// Rendered markdown
+    Content,
+
+    /// The [`NotificationCenter`](crate::NotificationCenter) dropdown
+    Dropdown,
+    /// Added to the dropdown while it is open
+    DropdownActive,
+    /// Added to the dropdown to align the menu to the right of the button
+    DropdownRight,
+    DropdownTrigger,
+    DropdownButton,
+    DropdownMenu,
+    DropdownContent,
+    DropdownItem,
+    DropdownDivider,
+}
+
+/// Maps colors, sizes and structural parts to CSS classes
+pub trait Theme {
+    fn part(&self, part: Part) -> Classes;
+    /// Classes of a color. [`Color::Custom`] colors also get their own class
+    /// and inline styles, see [`CustomColor`](crate::CustomColor)
+    fn color(&self, color: &Color) -> Classes;
+    fn size(&self, size: Size) -> Classes;
+}
+
+/// Classes of the [Bulma](https://bulma.io) CSS framework
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct BulmaTheme;
+
+impl Theme for BulmaTheme {
+    fn part(&self, part: Part) -> Classes {
+        Classes::from(match part {
+            Part::Message => "message",
+            Part::MessageHeader => "message-header",
+            Part::MessageBody => "message-body",
+            Part::Close => "delete",
+            Part::Buttons => "buttons",
+            Part::Button => "button is-small",
+            Part::TextButton => "button is-small is-text",
+            Part::Icon => "icon",
+            Part::Count => "tag is-danger is-rounded",
+            Part::BadgeButton => "button is-white",
+            Part::Content => "content",
+            Part::Dropdown => "dropdown",
+            Part::DropdownActive => "is-active",
+            Part::DropdownRight => "is-right",
+            Part::DropdownTrigger => "dropdown-trigger",
+            Part::DropdownButton => "button",
+            Part::DropdownMenu => "dropdown-menu",
+            Part::DropdownContent => "dropdown-content",
+            Part::DropdownItem => "dropdown-item",
+            Part::DropdownDivider => "dropdown-divider",
+        })
+    }
+
+    fn color(&self, color: &Color) -> Classes {
+        color.class().map(Classes::from).unwrap_or_default()
+    }
+
+    fn size(&self, size: Size) -> Classes {
+        size.class().map(Classes::from).unwrap_or_default()
+    }
+}
+
+/// Use `theme` for all components rendered from now on, on this thread
+pub fn set_theme(theme: impl Theme + 'static) {
+    THEME.with(|current| *current.borrow_mut() = Rc::new(theme));
+}
+
+/// The installed theme
+pub(crate) fn theme() -> Rc<dyn Theme> {
+    THEME.with(|current| current.borrow().clone())
+}